@@ -0,0 +1,193 @@
+use crate::cli::BenchOptions;
+use crate::registry;
+use common::input::{self, Source};
+use common::Solver;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub stddev_ns: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut nanos = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect::<Vec<f64>>();
+        nanos.sort_by(f64::total_cmp);
+
+        let count = nanos.len() as f64;
+        let mean_ns = nanos.iter().sum::<f64>() / count;
+        let middle = nanos.len() / 2;
+        let median_ns = if nanos.len() % 2 == 0 {
+            (nanos[middle - 1] + nanos[middle]) / 2.0
+        } else {
+            nanos[middle]
+        };
+        let variance = nanos.iter().map(|ns| (ns - mean_ns).powi(2)).sum::<f64>() / count;
+        Stats {
+            mean_ns,
+            median_ns,
+            stddev_ns: variance.sqrt(),
+        }
+    }
+}
+
+/// Stats per phase (`parse`, `part1`, `part2`) for one day.
+pub type Phases = BTreeMap<String, Stats>;
+
+/// What the baseline file holds: phases keyed by `dayN`.
+pub type Timings = BTreeMap<String, Phases>;
+
+/// Times each phase separately over `iterations` runs of the input `name`.
+/// Part 2 is left out for days that don't solve it.
+pub fn bench(
+    solver: &dyn Solver,
+    name: &str,
+    file_content: &str,
+    iterations: u32,
+) -> Result<Phases, String> {
+    let mut parse = vec![];
+    let mut part1 = vec![];
+    let mut part2 = vec![];
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = solver
+            .parse(black_box(file_content))
+            .map_err(|err| err.render(name, file_content))?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(solver.part1(parsed.as_ref())).map_err(|err| err.to_string())?;
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        let answer = black_box(solver.part2(parsed.as_ref()));
+        if let Some(answer) = answer {
+            answer.map_err(|err| err.to_string())?;
+            part2.push(start.elapsed());
+        }
+    }
+
+    let mut phases = Phases::new();
+    for (phase, samples) in [("parse", parse), ("part1", part1), ("part2", part2)] {
+        if !samples.is_empty() {
+            phases.insert(phase.to_string(), Stats::from_samples(&samples));
+        }
+    }
+    Ok(phases)
+}
+
+/// Percentage change of the median against the baseline.
+pub fn change(current: &Stats, baseline: &Stats) -> f64 {
+    (current.median_ns / baseline.median_ns - 1.0) * 100.0
+}
+
+fn load_baseline(path: &str) -> Result<Timings, String> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => {
+            serde_json::from_str(&text).map_err(|err| format!("invalid baseline {}: {}", path, err))
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Timings::new()),
+        Err(err) => Err(format!("could not read baseline {}: {}", path, err)),
+    }
+}
+
+fn save_baseline(path: &str, timings: &Timings) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(timings).map_err(|err| err.to_string())?;
+    std::fs::write(path, json + "\n").map_err(|err| format!("could not write {}: {}", path, err))
+}
+
+pub fn run(options: &BenchOptions) -> Result<(), String> {
+    let mut baseline = load_baseline(&options.baseline)?;
+    let mut regressions = 0;
+
+    for solver in registry::SOLUTIONS {
+        let day = solver.day();
+        if !options.days.contains(day) {
+            continue;
+        }
+        let source = Source::Day(day);
+        let file_content = input::load(&source).map_err(|err| err.to_string())?;
+        let phases = bench(*solver, &source.name(), &file_content, options.iterations)?;
+
+        let key = format!("day{}", day);
+        for (phase, stats) in &phases {
+            let previous = baseline.get(&key).and_then(|phases| phases.get(phase));
+            let comparison = match previous {
+                Some(previous) => {
+                    let change = change(stats, previous);
+                    if change > options.threshold {
+                        regressions += 1;
+                        format!("{:+.1}% REGRESSION", change)
+                    } else {
+                        format!("{:+.1}%", change)
+                    }
+                }
+                None => String::new(),
+            };
+            let line = format!(
+                "day {:<2} {:<5}  mean {:>10}  median {:>10}  stddev {:>10}  {}",
+                day,
+                phase,
+                format_nanos(stats.mean_ns),
+                format_nanos(stats.median_ns),
+                format_nanos(stats.stddev_ns),
+                comparison
+            );
+            println!("{}", line.trim_end());
+        }
+        if options.save_baseline {
+            baseline.insert(key, phases);
+        }
+    }
+
+    if options.save_baseline {
+        save_baseline(&options.baseline, &baseline)?;
+        println!("saved baseline to {}", options.baseline);
+    }
+    if regressions > 0 {
+        return Err(format!(
+            "{} phase(s) are more than {}% slower than the baseline",
+            regressions, options.threshold
+        ));
+    }
+    Ok(())
+}
+
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos as u64))
+}
+
+#[test]
+fn test_stats_from_samples() {
+    let samples = [4, 1, 3, 2].map(Duration::from_nanos);
+    assert_eq!(
+        Stats::from_samples(&samples),
+        Stats {
+            mean_ns: 2.5,
+            median_ns: 2.5,
+            stddev_ns: 1.25_f64.sqrt(),
+        }
+    );
+    let odd = Stats::from_samples(&[10, 30, 20].map(Duration::from_nanos));
+    assert_eq!(odd.median_ns, 20.0);
+}
+
+#[test]
+fn test_change() {
+    let stats = |median_ns| Stats {
+        mean_ns: median_ns,
+        median_ns,
+        stddev_ns: 0.0,
+    };
+    assert_eq!(change(&stats(110.0), &stats(100.0)).round(), 10.0);
+    assert_eq!(change(&stats(50.0), &stats(100.0)), -50.0);
+}
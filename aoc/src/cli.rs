@@ -0,0 +1,306 @@
+use std::ops::RangeInclusive;
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        days: DaySelection,
+        parts: PartSelection,
+        input: Option<String>,
+        format: Format,
+    },
+    Verify {
+        days: DaySelection,
+    },
+    Bench(BenchOptions),
+    New {
+        day: u8,
+    },
+    List,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchOptions {
+    pub days: DaySelection,
+    pub iterations: u32,
+    /// How much slower than the baseline, in percent, counts as a regression.
+    pub threshold: f64,
+    pub baseline: String,
+    pub save_baseline: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DaySelection {
+    All,
+    Range(RangeInclusive<u8>),
+}
+
+impl DaySelection {
+    pub fn contains(&self, day: u8) -> bool {
+        match self {
+            DaySelection::All => true,
+            DaySelection::Range(range) => range.contains(&day),
+        }
+    }
+
+    pub fn is_single(&self) -> bool {
+        match self {
+            DaySelection::All => false,
+            DaySelection::Range(range) => range.start() == range.end(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    /// One JSON object per line and day/part.
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PartSelection {
+    All,
+    One(u8),
+}
+
+impl PartSelection {
+    pub fn parts(&self) -> Vec<u8> {
+        match self {
+            PartSelection::All => vec![1, 2],
+            PartSelection::One(part) => vec![*part],
+        }
+    }
+}
+
+pub const USAGE: &str = "Usage:
+  aoc run <day> [part] [input]   run one day (3), a range (1-3 or 1..3) or all
+                                 days; part is 1, 2 or all (default all);
+                                 input is a path, - for stdin or a day number
+      --format <text|json>       output format (default text)
+  aoc verify [day]               check every input against answers.toml
+  aoc bench [day] [options]      time parse, part 1 and part 2 of each day
+      --iterations <n>           runs per phase (default 100)
+      --baseline <file>          baseline to compare with (default
+                                 bench-baseline.json)
+      --threshold <percent>      slowdown flagged as a regression (default 10)
+      --save-baseline            store this run as the new baseline
+  aoc new <day>                  create and register a crate for a new day
+  aoc list                       list the registered solutions";
+
+pub fn parse_args(args: &[String]) -> Result<Command, String> {
+    match args.first().map(String::as_str) {
+        Some("run") => parse_run(&args[1..]),
+        Some("verify") => {
+            let days = match args.get(1) {
+                Some(days) => parse_days(days)?,
+                None => DaySelection::All,
+            };
+            if args.len() > 2 {
+                return Err(format!("unexpected argument '{}'", args[2]));
+            }
+            Ok(Command::Verify { days })
+        }
+        Some("bench") => parse_bench(&args[1..]),
+        Some("new") => match &args[1..] {
+            [day] => Ok(Command::New {
+                day: parse_day(day)?,
+            }),
+            [] => Err("missing <day>".to_string()),
+            [_, extra, ..] => Err(format!("unexpected argument '{}'", extra)),
+        },
+        Some("list") => Ok(Command::List),
+        Some(other) => Err(format!("unknown command '{}'", other)),
+        None => Err("missing command".to_string()),
+    }
+}
+
+fn parse_run(args: &[String]) -> Result<Command, String> {
+    let (positional, flags) = split_flags(args, &["--format"], &[])?;
+    let days = parse_days(positional.first().ok_or("missing <day>")?)?;
+    let parts = match positional.get(1) {
+        Some(part) => parse_part(part)?,
+        None => PartSelection::All,
+    };
+    let input = positional.get(2).map(|input| input.to_string());
+    if input.is_some() && !days.is_single() {
+        return Err("an input file can only be given for a single day".to_string());
+    }
+    if let Some(extra) = positional.get(3) {
+        return Err(format!("unexpected argument '{}'", extra));
+    }
+
+    let mut format = Format::Text;
+    for (_, value) in flags {
+        format = match value.unwrap_or_default() {
+            "text" => Format::Text,
+            "json" => Format::Json,
+            other => return Err(format!("unknown format '{}'", other)),
+        };
+    }
+    Ok(Command::Run {
+        days,
+        parts,
+        input,
+        format,
+    })
+}
+
+fn parse_bench(args: &[String]) -> Result<Command, String> {
+    let (positional, flags) = split_flags(
+        args,
+        &["--iterations", "--baseline", "--threshold"],
+        &["--save-baseline"],
+    )?;
+    let mut options = BenchOptions {
+        days: DaySelection::All,
+        iterations: 100,
+        threshold: 10.0,
+        baseline: "bench-baseline.json".to_string(),
+        save_baseline: false,
+    };
+    match positional.as_slice() {
+        [] => (),
+        [days] => options.days = parse_days(days)?,
+        [_, extra, ..] => return Err(format!("unexpected argument '{}'", extra)),
+    }
+    for (flag, value) in flags {
+        let value = value.unwrap_or_default();
+        match flag {
+            "--iterations" => {
+                options.iterations = match value.parse::<u32>() {
+                    Ok(iterations) if iterations > 0 => iterations,
+                    _ => return Err(format!("'{}' is not a positive iteration count", value)),
+                }
+            }
+            "--threshold" => {
+                options.threshold = match value.parse::<f64>() {
+                    Ok(threshold) if threshold >= 0.0 => threshold,
+                    _ => return Err(format!("'{}' is not a percentage", value)),
+                }
+            }
+            "--baseline" => options.baseline = value.to_string(),
+            _ => options.save_baseline = true,
+        }
+    }
+    Ok(Command::Bench(options))
+}
+
+type Flag<'a> = (&'a str, Option<&'a str>);
+
+/// Separates positional arguments from flags; `with_value` flags take the
+/// next argument, `switches` stand alone.
+fn split_flags<'a>(
+    args: &'a [String],
+    with_value: &[&str],
+    switches: &[&str],
+) -> Result<(Vec<&'a str>, Vec<Flag<'a>>), String> {
+    let mut positional = vec![];
+    let mut flags = vec![];
+    let mut args = args.iter().map(String::as_str);
+    while let Some(arg) = args.next() {
+        if with_value.contains(&arg) {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("{} needs a value", arg))?;
+            flags.push((arg, Some(value)));
+        } else if switches.contains(&arg) {
+            flags.push((arg, None));
+        } else if arg.starts_with("--") {
+            return Err(format!("unknown option '{}'", arg));
+        } else {
+            positional.push(arg);
+        }
+    }
+    Ok((positional, flags))
+}
+
+fn parse_days(arg: &str) -> Result<DaySelection, String> {
+    if arg == "all" {
+        return Ok(DaySelection::All);
+    }
+    let bounds = arg.split_once("..").or_else(|| arg.split_once('-'));
+    let (start, end) = match bounds {
+        Some((start, end)) => (parse_day(start)?, parse_day(end)?),
+        None => (parse_day(arg)?, parse_day(arg)?),
+    };
+    if start > end {
+        return Err(format!("day range '{}' is empty", arg));
+    }
+    Ok(DaySelection::Range(start..=end))
+}
+
+fn parse_day(arg: &str) -> Result<u8, String> {
+    match arg.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("'{}' is not a day between 1 and 25", arg)),
+    }
+}
+
+fn parse_part(arg: &str) -> Result<PartSelection, String> {
+    match arg {
+        "1" => Ok(PartSelection::One(1)),
+        "2" => Ok(PartSelection::One(2)),
+        "all" => Ok(PartSelection::All),
+        _ => Err(format!("'{}' is not a part (expected 1, 2 or all)", arg)),
+    }
+}
+
+#[test]
+fn test_parse_days() {
+    assert_eq!(parse_days("all"), Ok(DaySelection::All));
+    assert_eq!(parse_days("3"), Ok(DaySelection::Range(3..=3)));
+    assert_eq!(parse_days("1-3"), Ok(DaySelection::Range(1..=3)));
+    assert_eq!(parse_days("2..4"), Ok(DaySelection::Range(2..=4)));
+    assert!(parse_days("4-2").is_err());
+    assert!(parse_days("0").is_err());
+    assert!(parse_days("26").is_err());
+    assert!(parse_days("one").is_err());
+}
+
+#[test]
+fn test_parse_args() {
+    let args = |s: &str| s.split(' ').map(String::from).collect::<Vec<String>>();
+    assert_eq!(
+        parse_args(&args("run 2 1 day2/sample.txt")),
+        Ok(Command::Run {
+            days: DaySelection::Range(2..=2),
+            parts: PartSelection::One(1),
+            input: Some("day2/sample.txt".to_string()),
+            format: Format::Text,
+        })
+    );
+    assert_eq!(
+        parse_args(&args("run all --format json")),
+        Ok(Command::Run {
+            days: DaySelection::All,
+            parts: PartSelection::All,
+            input: None,
+            format: Format::Json,
+        })
+    );
+    assert!(parse_args(&args("run all --format yaml")).is_err());
+    assert!(parse_args(&args("run 1-3 1 input.txt")).is_err());
+    assert!(parse_args(&args("run 1 3")).is_err());
+    assert_eq!(
+        parse_args(&args("verify 1..2")),
+        Ok(Command::Verify {
+            days: DaySelection::Range(1..=2),
+        })
+    );
+    assert_eq!(
+        parse_args(&args("bench 4 --iterations 5 --save-baseline")),
+        Ok(Command::Bench(BenchOptions {
+            days: DaySelection::Range(4..=4),
+            iterations: 5,
+            threshold: 10.0,
+            baseline: "bench-baseline.json".to_string(),
+            save_baseline: true,
+        }))
+    );
+    assert!(parse_args(&args("bench --iterations 0")).is_err());
+    assert!(parse_args(&args("bench --threshold")).is_err());
+    assert!(parse_args(&args("bench --fast")).is_err());
+    assert_eq!(parse_args(&args("new 5")), Ok(Command::New { day: 5 }));
+    assert!(parse_args(&args("new 26")).is_err());
+    assert!(parse_args(&args("fly")).is_err());
+}
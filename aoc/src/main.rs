@@ -0,0 +1,58 @@
+mod bench;
+mod cli;
+mod registry;
+mod run;
+mod scaffold;
+mod verify;
+
+use cli::{Command, DaySelection};
+use common::answers::{Answers, ANSWERS_FILE};
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = match cli::parse_args(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let result = match command {
+        Command::Run {
+            days,
+            parts,
+            input,
+            format,
+        } => run::run(&days, &parts, input.as_deref(), format),
+        Command::Verify { days } => verify(&days),
+        Command::Bench(options) => bench::run(&options),
+        Command::New { day } => scaffold::new_day(day),
+        Command::List => {
+            list();
+            Ok(())
+        }
+    };
+
+    if let Err(message) = result {
+        eprintln!("error: {}", message);
+        std::process::exit(1);
+    }
+}
+
+fn verify(days: &DaySelection) -> Result<(), String> {
+    let answers = Answers::load(ANSWERS_FILE).map_err(|err| err.to_string())?;
+    let checks = verify::verify(days, &answers);
+    if verify::report(&checks) {
+        Ok(())
+    } else {
+        Err("some answers no longer match".to_string())
+    }
+}
+
+fn list() {
+    for solver in registry::SOLUTIONS {
+        let parts = if solver.solves_part2() { "1, 2" } else { "1" };
+        println!("day {}: parts {}", solver.day(), parts);
+    }
+}
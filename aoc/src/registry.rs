@@ -0,0 +1,4 @@
+use common::Solver;
+
+/// Every solution the runner knows about, in day order.
+pub const SOLUTIONS: &[&dyn Solver] = &[&day1::Day1, &day2::Day2, &day3::Day3, &day4::Day4];
@@ -0,0 +1,267 @@
+use crate::cli::{DaySelection, Format, PartSelection};
+use crate::registry;
+use common::input::{self, Source};
+use common::{ParseError, Solver};
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+/// The result of running one part of one day, as printed by `aoc run`.
+#[derive(Debug, Serialize)]
+pub struct Outcome {
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub input: String,
+    pub answer: Option<String>,
+    pub timing: Option<Timing>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Error,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Timing {
+    pub parse_ns: u128,
+    pub solve_ns: u128,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum Diagnostic {
+    Parse(ParseError),
+    Message { message: String },
+}
+
+impl Outcome {
+    fn failed(day: u8, part: u8, status: Status, input: &str, diagnostic: Diagnostic) -> Outcome {
+        Outcome {
+            day,
+            part,
+            status,
+            input: input.to_string(),
+            answer: None,
+            timing: None,
+            diagnostics: vec![diagnostic],
+        }
+    }
+}
+
+/// Runs one day on `source`. A part that isn't solved yields an outcome only
+/// when `parts` asked for it explicitly.
+pub fn run_day(solver: &dyn Solver, source: &Source, parts: &PartSelection) -> Vec<Outcome> {
+    let day = solver.day();
+    let name = source.name();
+    let file_content = match input::load(source) {
+        Ok(file_content) => file_content,
+        Err(err) => {
+            let message = err.to_string();
+            return parts
+                .parts()
+                .into_iter()
+                .map(|part| {
+                    Outcome::failed(
+                        day,
+                        part,
+                        Status::Error,
+                        &name,
+                        Diagnostic::Message {
+                            message: message.clone(),
+                        },
+                    )
+                })
+                .collect();
+        }
+    };
+
+    let start = Instant::now();
+    let parsed = solver.parse(&file_content);
+    let parse_time = start.elapsed();
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("error: {}", err.render(&name, &file_content));
+            return parts
+                .parts()
+                .into_iter()
+                .map(|part| {
+                    Outcome::failed(
+                        day,
+                        part,
+                        Status::Error,
+                        &name,
+                        Diagnostic::Parse(err.clone()),
+                    )
+                })
+                .collect();
+        }
+    };
+
+    let mut outcomes = vec![];
+    for part in parts.parts() {
+        let start = Instant::now();
+        let answer = match part {
+            1 => Some(solver.part1(parsed.as_ref())),
+            _ => solver.part2(parsed.as_ref()),
+        };
+        let solve_time = start.elapsed();
+        let outcome = match answer {
+            Some(Err(err)) => Outcome::failed(
+                day,
+                part,
+                Status::Error,
+                &name,
+                Diagnostic::Message {
+                    message: err.to_string(),
+                },
+            ),
+            Some(Ok(answer)) => Outcome {
+                day,
+                part,
+                status: Status::Solved,
+                input: name.clone(),
+                answer: Some(answer),
+                timing: Some(Timing {
+                    parse_ns: parse_time.as_nanos(),
+                    solve_ns: solve_time.as_nanos(),
+                }),
+                diagnostics: vec![],
+            },
+            None if matches!(parts, PartSelection::One(_)) => {
+                let message = "not solved".to_string();
+                Outcome::failed(
+                    day,
+                    part,
+                    Status::Unsolved,
+                    &name,
+                    Diagnostic::Message { message },
+                )
+            }
+            None => continue,
+        };
+        outcomes.push(outcome);
+    }
+    outcomes
+}
+
+pub fn run(
+    days: &DaySelection,
+    parts: &PartSelection,
+    input: Option<&str>,
+    format: Format,
+) -> Result<(), String> {
+    let selected = registry::SOLUTIONS
+        .iter()
+        .filter(|solver| days.contains(solver.day()))
+        .collect::<Vec<_>>();
+    if selected.is_empty() {
+        return Err("no registered solution for the selected days".to_string());
+    }
+
+    let mut failures = 0;
+    for solver in selected {
+        let source = match input {
+            Some(arg) => Source::from_arg(arg),
+            None => Source::Day(solver.day()),
+        };
+        for outcome in run_day(*solver, &source, parts) {
+            if outcome.status == Status::Error {
+                failures += 1;
+            }
+            match format {
+                Format::Text => print_text(&outcome),
+                Format::Json => println!(
+                    "{}",
+                    serde_json::to_string(&outcome).map_err(|err| err.to_string())?
+                ),
+            }
+        }
+    }
+
+    if failures > 0 {
+        return Err(format!("{} part(s) failed", failures));
+    }
+    Ok(())
+}
+
+fn print_text(outcome: &Outcome) {
+    let label = format!("day {} part {}", outcome.day, outcome.part);
+    match (&outcome.answer, &outcome.timing) {
+        (Some(answer), Some(timing)) => {
+            let elapsed = Duration::from_nanos(timing.solve_ns as u64);
+            println!("{}: {} ({:.2?})", label, answer, elapsed)
+        }
+        _ => {
+            for diagnostic in &outcome.diagnostics {
+                match diagnostic {
+                    // The full diagnostic went to stderr when parsing failed.
+                    Diagnostic::Parse(err) => println!("{}: {}", label, err),
+                    Diagnostic::Message { message } => println!("{}: {}", label, message),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_outcome_json() {
+    let outcome = Outcome {
+        day: 1,
+        part: 2,
+        status: Status::Solved,
+        input: "day1/input.txt".to_string(),
+        answer: Some("54676".to_string()),
+        timing: Some(Timing {
+            parse_ns: 10,
+            solve_ns: 20,
+        }),
+        diagnostics: vec![],
+    };
+    assert_eq!(
+        serde_json::to_string(&outcome).unwrap(),
+        r#"{"day":1,"part":2,"status":"solved","input":"day1/input.txt","answer":"54676","timing":{"parse_ns":10,"solve_ns":20},"diagnostics":[]}"#
+    );
+
+    let failed = Outcome::failed(
+        3,
+        2,
+        Status::Unsolved,
+        "day3/input.txt",
+        Diagnostic::Message {
+            message: "not solved".to_string(),
+        },
+    );
+    assert_eq!(
+        serde_json::to_string(&failed).unwrap(),
+        r#"{"day":3,"part":2,"status":"unsolved","input":"day3/input.txt","answer":null,"timing":null,"diagnostics":[{"message":"not solved"}]}"#
+    );
+}
+
+#[test]
+fn test_run_day_reports_solve_errors() {
+    let path = std::env::temp_dir().join(format!("aoc-overflow-{}.txt", std::process::id()));
+    std::fs::write(
+        &path,
+        "Game 1: 4294967295 red, 4294967295 green, 4294967295 blue",
+    )
+    .unwrap();
+    let outcomes = run_day(
+        &day2::Day2,
+        &Source::Path(path.clone()),
+        &PartSelection::All,
+    );
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(outcomes[0].status, Status::Solved);
+    assert_eq!(outcomes[0].answer.as_deref(), Some("0"));
+    assert_eq!(outcomes[1].status, Status::Error);
+    assert!(matches!(
+        &outcomes[1].diagnostics[..],
+        [Diagnostic::Message { message }] if message == "the power of game 1 does not fit in a u64"
+    ));
+}
@@ -0,0 +1,212 @@
+use std::fs;
+use std::path::Path;
+
+const CARGO_TOML: &str = r#"[package]
+name = "day{day}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+common = { path = "../common" }
+
+[[bin]]
+name = "day{day}-part1"
+path = "src/bin/part1.rs"
+"#;
+
+const LIB_RS: &str = r#"use common::{ParseError, Solution, SolveError};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u8 = {day};
+
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    // A placeholder: the sample below expects 1, so its test fails until
+    // this is solved.
+    fn part1(_lines: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(0)
+    }
+}
+
+common::samples! {
+    Day{day},
+    sample: "sample.txt" => { part1: 1 },
+}
+"#;
+
+const PART1_RS: &str = r#"use common::{input, Solution};
+use day{day}::Day{day};
+
+fn main() {
+    let (source, file_content) = input::from_env();
+    let lines =
+        Day{day}::parse(&file_content).unwrap_or_else(|err| err.exit(&source.name(), &file_content));
+    println!("{}", Day{day}::part1(&lines).unwrap_or_else(|err| err.exit()));
+}
+"#;
+
+/// Creates `dayN` from the template and registers it with the workspace,
+/// the runner and the answers file.
+pub fn new_day(day: u8) -> Result<(), String> {
+    let dir = format!("day{}", day);
+    if Path::new(&dir).exists() {
+        return Err(format!("{} already exists", dir));
+    }
+
+    let render = |template: &str| template.replace("{day}", &day.to_string());
+    let files = [
+        ("Cargo.toml", render(CARGO_TOML)),
+        ("src/lib.rs", render(LIB_RS)),
+        ("src/bin/part1.rs", render(PART1_RS)),
+        ("sample.txt", String::new()),
+        ("input.txt", String::new()),
+    ];
+    fs::create_dir_all(format!("{}/src/bin", dir)).map_err(|err| err.to_string())?;
+    for (file, content) in files {
+        write(&format!("{}/{}", dir, file), &content)?;
+    }
+
+    update("Cargo.toml", |text| add_workspace_member(text, day))?;
+    update("aoc/Cargo.toml", |text| add_dependency(text, day))?;
+    update("aoc/src/registry.rs", |text| add_solution(text, day))?;
+    update(common::answers::ANSWERS_FILE, |text| {
+        Ok(add_answers(text, day))
+    })?;
+
+    println!(
+        "created {}; fill in {}/sample.txt and its answer in {}/src/lib.rs",
+        dir, dir, dir
+    );
+    Ok(())
+}
+
+fn write(path: &str, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|err| format!("could not write {}: {}", path, err))
+}
+
+fn update(path: &str, edit: impl Fn(&str) -> Result<String, String>) -> Result<(), String> {
+    let text =
+        fs::read_to_string(path).map_err(|err| format!("could not read {}: {}", path, err))?;
+    write(
+        path,
+        &edit(&text).map_err(|err| format!("{}: {}", path, err))?,
+    )
+}
+
+fn add_workspace_member(cargo_toml: &str, day: u8) -> Result<String, String> {
+    let start = cargo_toml
+        .find("members = [")
+        .ok_or("no workspace members")?;
+    let end = start
+        + cargo_toml[start..]
+            .find(']')
+            .ok_or("unterminated members")?;
+    Ok(format!(
+        "{}, \"day{}\"{}",
+        &cargo_toml[..end],
+        day,
+        &cargo_toml[end..]
+    ))
+}
+
+fn add_dependency(cargo_toml: &str, day: u8) -> Result<String, String> {
+    let mut text = cargo_toml.trim_end().to_string();
+    if !text.contains("[dependencies]") {
+        return Err("no [dependencies] section".to_string());
+    }
+    text.push_str(&format!("\nday{} = {{ path = \"../day{}\" }}\n", day, day));
+    Ok(text)
+}
+
+/// Appends `&dayN::DayN` to `SOLUTIONS`, laid out the way rustfmt would.
+fn add_solution(registry: &str, day: u8) -> Result<String, String> {
+    const HEAD: &str = "pub const SOLUTIONS: &[&dyn Solver] = &[";
+    let start = registry.find(HEAD).ok_or("no SOLUTIONS list")?;
+    let body_start = start + HEAD.len();
+    let body_end = body_start
+        + registry[body_start..]
+            .find("];")
+            .ok_or("unterminated SOLUTIONS")?;
+
+    let mut entries = registry[body_start..body_end]
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(String::from)
+        .collect::<Vec<String>>();
+    entries.push(format!("&day{}::Day{}", day, day));
+
+    let single_line = format!("{}{}];", HEAD, entries.join(", "));
+    let list = if single_line.len() <= 100 {
+        single_line
+    } else {
+        let lines = entries
+            .iter()
+            .map(|entry| format!("    {},\n", entry))
+            .collect::<String>();
+        format!("{}\n{}];", HEAD, lines)
+    };
+    Ok(format!(
+        "{}{}{}",
+        &registry[..start],
+        list,
+        &registry[body_end + 2..]
+    ))
+}
+
+fn add_answers(answers: &str, day: u8) -> String {
+    format!(
+        "{}\n\n[day{}]\n# \"input.txt\" = {{ part1 = 0 }}\n",
+        answers.trim_end(),
+        day
+    )
+}
+
+#[test]
+fn test_add_workspace_member() {
+    assert_eq!(
+        add_workspace_member("[workspace]\nmembers = [\"aoc\", \"day1\"]\n", 2),
+        Ok("[workspace]\nmembers = [\"aoc\", \"day1\", \"day2\"]\n".to_string())
+    );
+    assert!(add_workspace_member("[package]\n", 2).is_err());
+}
+
+#[test]
+fn test_add_solution() {
+    let registry = "use common::Solver;\n\npub const SOLUTIONS: &[&dyn Solver] = &[&day1::Day1];\n";
+    assert_eq!(
+        add_solution(registry, 2),
+        Ok("use common::Solver;\n\npub const SOLUTIONS: &[&dyn Solver] = &[&day1::Day1, &day2::Day2];\n".to_string())
+    );
+
+    let long = "pub const SOLUTIONS: &[&dyn Solver] = &[&day1::Day1, &day2::Day2, &day3::Day3, &day4::Day4];\n";
+    assert_eq!(
+        add_solution(long, 5),
+        Ok("pub const SOLUTIONS: &[&dyn Solver] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+];
+"
+        .to_string())
+    );
+    assert_eq!(
+        add_solution(&add_solution(long, 5).unwrap(), 6)
+            .unwrap()
+            .matches("Day6")
+            .count(),
+        1
+    );
+}
@@ -0,0 +1,181 @@
+use crate::cli::DaySelection;
+use crate::registry;
+use common::answers::Answers;
+use common::input::{self, Source};
+use common::Solver;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+pub struct Check {
+    pub day: u8,
+    pub file: String,
+    /// `None` when the whole input failed to load or parse.
+    pub part: Option<u8>,
+    pub status: Status,
+}
+
+pub enum Status {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing { actual: String },
+    Error(String),
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Fail { .. } | Status::Error(_))
+    }
+}
+
+/// The recorded answers for parts 1 and 2 of one input file.
+type Expected = [Option<String>; 2];
+
+/// Runs every selected solver over its inputs and compares against the
+/// answers in the answers file and the day's declared samples. Files that
+/// appear in neither show up as missing.
+pub fn verify(days: &DaySelection, answers: &Answers) -> Vec<Check> {
+    let mut checks = vec![];
+    for solver in registry::SOLUTIONS {
+        let day = solver.day();
+        if !days.contains(day) {
+            continue;
+        }
+
+        let mut files: BTreeMap<String, Option<Expected>> = input_files(day)
+            .into_iter()
+            .map(|file| (file, None))
+            .collect();
+        for (file, expected) in answers.inputs(day) {
+            let part = |part| expected.part(part).map(|answer| answer.to_string());
+            files.insert(file.to_string(), Some([part(1), part(2)]));
+        }
+        for sample in solver.samples() {
+            let expected = [sample.expected(1), sample.expected(2)];
+            files.insert(sample.file.to_string(), Some(expected));
+        }
+
+        for (file, expected) in &files {
+            checks.extend(verify_input(*solver, day, file, expected.as_ref()));
+        }
+    }
+    checks
+}
+
+fn verify_input(
+    solver: &dyn Solver,
+    day: u8,
+    file: &str,
+    expected: Option<&Expected>,
+) -> Vec<Check> {
+    let check = |part, status| Check {
+        day,
+        file: file.to_string(),
+        part,
+        status,
+    };
+
+    let source = Source::Path(PathBuf::from(format!("day{}/{}", day, file)));
+    let file_content = match input::load(&source) {
+        Ok(file_content) => file_content,
+        Err(err) => return vec![check(None, Status::Error(err.to_string()))],
+    };
+    let parsed = match solver.parse(&file_content) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            let diagnostic = err.render(&source.name(), &file_content);
+            return vec![check(None, Status::Error(diagnostic))];
+        }
+    };
+
+    let mut checks = vec![];
+    for part in [1, 2] {
+        // Samples usually only apply to one part, so only the listed parts
+        // of a listed sample count.
+        let expected_answer = expected.and_then(|expected| expected[part as usize - 1].as_ref());
+        if expected_answer.is_none() && expected.is_some() && file != "input.txt" {
+            continue;
+        }
+
+        let actual = match part {
+            1 => Some(solver.part1(parsed.as_ref())),
+            _ => solver.part2(parsed.as_ref()),
+        };
+        let actual = match actual {
+            Some(Err(err)) => {
+                checks.push(check(Some(part), Status::Error(err.to_string())));
+                continue;
+            }
+            Some(Ok(actual)) => Some(actual),
+            None => None,
+        };
+        let status = match (actual, expected_answer) {
+            (Some(actual), Some(expected)) if actual == *expected => Status::Pass,
+            (Some(actual), Some(expected)) => Status::Fail {
+                expected: expected.clone(),
+                actual,
+            },
+            (None, Some(expected)) => Status::Fail {
+                expected: expected.clone(),
+                actual: "<not solved>".to_string(),
+            },
+            (Some(actual), None) => Status::Missing { actual },
+            (None, None) => continue,
+        };
+        checks.push(check(Some(part), status));
+    }
+    checks
+}
+
+/// `input.txt` and every `sample*.txt` in the day's directory.
+fn input_files(day: u8) -> BTreeSet<String> {
+    let Ok(entries) = std::fs::read_dir(format!("day{}", day)) else {
+        return BTreeSet::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| {
+            name == "input.txt" || (name.starts_with("sample") && name.ends_with(".txt"))
+        })
+        .collect()
+}
+
+/// Prints one line per check, with a diff for mismatches, and returns
+/// whether everything that was recorded still matches.
+pub fn report(checks: &[Check]) -> bool {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for check in checks {
+        let part = match check.part {
+            Some(part) => format!(" part {}", part),
+            None => String::new(),
+        };
+        let label = format!("day {}{} ({})", check.day, part, check.file);
+        match &check.status {
+            Status::Pass => {
+                passed += 1;
+                println!("pass    {}", label);
+            }
+            Status::Fail { expected, actual } => {
+                failed += 1;
+                println!("FAIL    {}", label);
+                println!("        - expected: {}", expected);
+                println!("        + actual:   {}", actual);
+            }
+            Status::Missing { actual } => {
+                missing += 1;
+                println!("missing {}: got {}", label, actual);
+            }
+            Status::Error(message) => {
+                failed += 1;
+                println!("ERROR   {}", label);
+                for line in message.lines() {
+                    println!("        {}", line);
+                }
+            }
+        }
+    }
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        passed, failed, missing
+    );
+    !checks.iter().any(|check| check.status.is_failure())
+}
@@ -0,0 +1,120 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+/// The checked-in answers file, relative to the repository root.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known-correct answers, keyed by day and then by input file name
+/// (relative to the day's directory):
+///
+/// ```toml
+/// [day1]
+/// "input.txt" = { part1 = 53921, part2 = 54676 }
+/// "sample.part2.txt" = { part2 = 281 }
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, BTreeMap<String, Expected>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Answers are usually numbers but some puzzles want text, so both are
+/// accepted and compared by their rendering.
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(std::io::Error),
+    Toml(toml::de::Error),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnswersError::Io(err) => write!(f, "could not read {}: {}", ANSWERS_FILE, err),
+            AnswersError::Toml(err) => write!(f, "invalid {}: {}", ANSWERS_FILE, err),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+impl Answers {
+    pub fn load(path: impl AsRef<Path>) -> Result<Answers, AnswersError> {
+        let text = std::fs::read_to_string(path).map_err(AnswersError::Io)?;
+        Answers::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Answers, AnswersError> {
+        toml::from_str(text).map_err(AnswersError::Toml)
+    }
+
+    /// Every input with recorded answers for `day`, by file name.
+    pub fn inputs(&self, day: u8) -> impl Iterator<Item = (&str, &Expected)> {
+        self.days
+            .get(&format!("day{}", day))
+            .into_iter()
+            .flatten()
+            .map(|(file, expected)| (file.as_str(), expected))
+    }
+
+    pub fn get(&self, day: u8, file: &str, part: u8) -> Option<&Answer> {
+        self.inputs(day)
+            .find(|(name, _)| *name == file)
+            .and_then(|(_, expected)| expected.part(part))
+    }
+}
+
+#[test]
+fn test_parse_answers() {
+    let answers = Answers::parse(
+        r#"
+[day1]
+"input.txt" = { part1 = 53921, part2 = "54676" }
+"sample.part2.txt" = { part2 = 281 }
+"#,
+    )
+    .unwrap();
+    assert_eq!(answers.get(1, "input.txt", 1), Some(&Answer::Number(53921)));
+    assert_eq!(
+        answers.get(1, "input.txt", 2).map(Answer::to_string),
+        Some("54676".to_string())
+    );
+    assert_eq!(answers.get(1, "sample.part2.txt", 1), None);
+    assert_eq!(answers.get(2, "input.txt", 1), None);
+    assert_eq!(answers.inputs(1).count(), 2);
+    assert!(Answers::parse("[day1]\n\"input.txt\" = { part3 = 1 }").is_err());
+}
@@ -0,0 +1,181 @@
+use crate::fetch::{FetchError, Fetcher, HttpFetcher};
+use crate::input::InputError;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Remembers when the last fetch happened, so the rate limit holds across
+/// runs.
+const LAST_FETCH_FILE: &str = ".last-fetch";
+
+/// Puzzle inputs stored as `<dir>/dayN/input.txt`. An input that is missing
+/// (or empty, like the placeholder `aoc new` creates) is fetched once and
+/// kept; cached inputs are never fetched again.
+pub struct InputCache {
+    pub dir: PathBuf,
+    pub fetcher: Box<dyn Fetcher>,
+    /// Shortest time allowed between two fetches.
+    pub min_interval: Duration,
+}
+
+/// The cache directory: `AOC_INPUT_DIR`, or the repository root.
+pub fn input_dir() -> PathBuf {
+    std::env::var_os("AOC_INPUT_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default()
+}
+
+pub fn input_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{}", day)).join("input.txt")
+}
+
+impl InputCache {
+    pub fn from_env() -> InputCache {
+        InputCache {
+            dir: input_dir(),
+            fetcher: Box::new(HttpFetcher::from_env()),
+            min_interval: Duration::from_secs(5),
+        }
+    }
+
+    pub fn get(&self, day: u8) -> Result<String, InputError> {
+        let path = input_path(&self.dir, day);
+        let name = path.display().to_string();
+        let io_error = |source| InputError::Io {
+            name: name.clone(),
+            source,
+        };
+        match fs::read_to_string(&path) {
+            Ok(text) if !text.trim().is_empty() => return Ok(text),
+            Ok(_) => (),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => (),
+            Err(err) => return Err(io_error(err)),
+        }
+
+        let fetch_error = |source| InputError::Fetch {
+            name: name.clone(),
+            source,
+        };
+        self.wait_for_rate_limit();
+        let fetched = self.fetcher.fetch(day);
+        if !matches!(fetched, Err(FetchError::MissingSession)) {
+            self.record_fetch().map_err(io_error)?;
+        }
+        let text = fetched.map_err(fetch_error)?;
+        if text.trim().is_empty() {
+            return Err(fetch_error(FetchError::Empty));
+        }
+
+        // Write next to the target and rename, so an interrupted run never
+        // leaves a truncated input that would count as cached.
+        let partial = path.with_extension("txt.partial");
+        fs::create_dir_all(path.parent().unwrap_or(&self.dir)).map_err(io_error)?;
+        fs::write(&partial, &text).map_err(io_error)?;
+        fs::rename(&partial, &path).map_err(io_error)?;
+        Ok(text)
+    }
+
+    fn wait_for_rate_limit(&self) {
+        let Some(last) = self.last_fetch() else {
+            return;
+        };
+        let since = SystemTime::now()
+            .duration_since(last)
+            .unwrap_or(Duration::ZERO);
+        if since < self.min_interval {
+            std::thread::sleep(self.min_interval - since);
+        }
+    }
+
+    fn last_fetch(&self) -> Option<SystemTime> {
+        let nanos = fs::read_to_string(self.dir.join(LAST_FETCH_FILE)).ok()?;
+        let nanos = nanos.trim().parse::<u64>().ok()?;
+        Some(UNIX_EPOCH + Duration::from_nanos(nanos))
+    }
+
+    fn record_fetch(&self) -> std::io::Result<()> {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO)
+            .as_nanos();
+        if !self.dir.as_os_str().is_empty() {
+            fs::create_dir_all(&self.dir)?;
+        }
+        fs::write(self.dir.join(LAST_FETCH_FILE), nanos.to_string())
+    }
+}
+
+#[cfg(test)]
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-cache-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn test_fetches_once_from_server() {
+    let (base_url, requests) = crate::fetch::serve(200, "Game 1: 3 blue\n");
+    let dir = temp_dir("server");
+    let cache = InputCache {
+        dir: dir.clone(),
+        fetcher: Box::new(HttpFetcher {
+            base_url,
+            session: Some("cafe".to_string()),
+        }),
+        min_interval: Duration::ZERO,
+    };
+
+    assert_eq!(cache.get(2).unwrap(), "Game 1: 3 blue\n");
+    assert_eq!(cache.get(2).unwrap(), "Game 1: 3 blue\n");
+    assert_eq!(
+        fs::read_to_string(dir.join("day2/input.txt")).unwrap(),
+        "Game 1: 3 blue\n"
+    );
+    assert!(requests.recv().unwrap().contains("GET /2023/day/2/input"));
+    assert!(requests.try_recv().is_err());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[cfg(test)]
+struct Canned(&'static str);
+
+#[cfg(test)]
+impl Fetcher for Canned {
+    fn fetch(&self, _day: u8) -> Result<String, FetchError> {
+        Ok(self.0.to_string())
+    }
+}
+
+#[test]
+fn test_rejects_empty_input() {
+    let dir = temp_dir("empty");
+    let cache = InputCache {
+        dir: dir.clone(),
+        fetcher: Box::new(Canned("\n")),
+        min_interval: Duration::ZERO,
+    };
+    assert!(matches!(
+        cache.get(3),
+        Err(InputError::Fetch {
+            source: FetchError::Empty,
+            ..
+        })
+    ));
+    assert!(!dir.join("day3/input.txt").exists());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_rate_limits_fetches() {
+    let dir = temp_dir("rate");
+    let cache = InputCache {
+        dir: dir.clone(),
+        fetcher: Box::new(Canned("467..114..")),
+        min_interval: Duration::from_millis(200),
+    };
+    let start = std::time::Instant::now();
+    cache.get(1).unwrap();
+    cache.get(2).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(200));
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,136 @@
+use serde::Serialize;
+use std::fmt;
+
+/// A malformed piece of puzzle input. Lines and columns are 1-based and
+/// columns count characters, not bytes.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error about `offending`, which must be a slice of `line`; its
+    /// position in `line` gives the column. The line number starts out as 1
+    /// and is set with [`ParseError::on_line`] by whoever splits the input.
+    pub fn at(line: &str, offending: &str, message: impl Into<String>) -> ParseError {
+        let offset = (offending.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|offset| offset + offending.len() <= line.len())
+            .unwrap_or(0);
+        ParseError {
+            line: 1,
+            column: line[..offset].chars().count() + 1,
+            text: offending.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn on_line(self, line: usize) -> ParseError {
+        ParseError { line, ..self }
+    }
+
+    /// A compiler-style diagnostic quoting the offending line of `source`,
+    /// with a caret under the offending text.
+    pub fn render(&self, name: &str, source: &str) -> String {
+        let quoted = source.lines().nth(self.line - 1).unwrap_or("");
+        let gutter = " ".repeat(self.line.to_string().len());
+        let padding = " ".repeat(self.column - 1);
+        let carets = "^".repeat(self.text.chars().count().max(1));
+        format!(
+            "{}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}",
+            self.message,
+            gutter,
+            name,
+            self.line,
+            self.column,
+            gutter,
+            self.line,
+            quoted,
+            gutter,
+            padding,
+            carets
+        )
+    }
+
+    /// Prints the diagnostic for the day binaries, naming the input `name`,
+    /// and exits.
+    pub fn exit(&self, name: &str, source: &str) -> ! {
+        eprintln!("error: {}", self.render(name, source));
+        std::process::exit(1);
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} ('{}')",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Why a part has no answer although its input parsed, e.g. because the
+/// answer overflows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveError {
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> SolveError {
+        SolveError {
+            message: message.into(),
+        }
+    }
+
+    /// Prints the error for the day binaries and exits.
+    pub fn exit(&self) -> ! {
+        eprintln!("error: {}", self);
+        std::process::exit(1);
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+#[test]
+fn test_at_finds_column() {
+    let line = "Game 7: 3 blüe, x red";
+    let count = &line[line.find('x').unwrap()..][..1];
+    let err = ParseError::at(line, count, "invalid count").on_line(7);
+    assert_eq!(
+        err,
+        ParseError {
+            line: 7,
+            column: 17,
+            text: "x".to_string(),
+            message: "invalid count".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_render() {
+    let source = "Game 1: 3 blue\nGame 2: 4 rd";
+    let line = source.lines().nth(1).unwrap();
+    let err = ParseError::at(line, &line[10..], "unknown colour").on_line(2);
+    assert_eq!(
+        err.render("day2/input.txt", source),
+        "unknown colour
+ --> day2/input.txt:2:11
+  |
+2 | Game 2: 4 rd
+  |           ^^"
+    );
+}
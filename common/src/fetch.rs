@@ -0,0 +1,160 @@
+use std::fmt;
+
+/// The puzzle year, used to build input URLs.
+pub const YEAR: u16 = 2023;
+
+/// Something that can produce a day's puzzle input; the cache only calls it
+/// when it doesn't hold the input yet.
+pub trait Fetcher {
+    fn fetch(&self, day: u8) -> Result<String, FetchError>;
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    MissingSession,
+    Status { url: String, status: u16 },
+    Transport { url: String, message: String },
+    Empty,
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(f, "AOC_SESSION is not set"),
+            FetchError::Status { url, status } => write!(f, "{} answered {}", url, status),
+            FetchError::Transport { url, message } => {
+                write!(f, "could not fetch {}: {}", url, message)
+            }
+            FetchError::Empty => write!(f, "the fetched input is empty"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Fetches `<base_url>/<year>/day/<day>/input` with the session cookie, the
+/// way the puzzle site serves inputs.
+pub struct HttpFetcher {
+    pub base_url: String,
+    pub session: Option<String>,
+}
+
+impl HttpFetcher {
+    /// Reads `AOC_BASE_URL` (default `https://adventofcode.com`) and
+    /// `AOC_SESSION`.
+    pub fn from_env() -> HttpFetcher {
+        HttpFetcher {
+            base_url: std::env::var("AOC_BASE_URL")
+                .unwrap_or_else(|_| "https://adventofcode.com".to_string()),
+            session: std::env::var("AOC_SESSION").ok(),
+        }
+    }
+
+    pub fn url(&self, day: u8) -> String {
+        format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            YEAR,
+            day
+        )
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, day: u8) -> Result<String, FetchError> {
+        let session = self.session.as_ref().ok_or(FetchError::MissingSession)?;
+        let url = self.url(day);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .set(
+                "User-Agent",
+                "github.com/alexanderlhc/2023-adventofcode input cache",
+            )
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(status, _) => FetchError::Status {
+                    url: url.clone(),
+                    status,
+                },
+                ureq::Error::Transport(transport) => FetchError::Transport {
+                    url: url.clone(),
+                    message: transport.to_string(),
+                },
+            })?;
+        response.into_string().map_err(|err| FetchError::Transport {
+            url,
+            message: err.to_string(),
+        })
+    }
+}
+
+/// A stand-in for the puzzle site: answers every request with `status` and
+/// `body`, and hands back the raw requests it received.
+#[cfg(test)]
+pub(crate) fn serve(
+    status: u16,
+    body: &'static str,
+) -> (String, std::sync::mpsc::Receiver<String>) {
+    use std::io::{BufRead, BufReader, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let (requests, received) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut request = String::new();
+            let mut reader = BufReader::new(&stream);
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                    break;
+                }
+                request.push_str(&line);
+            }
+            let response = format!(
+                "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+            if requests.send(request).is_err() {
+                break;
+            }
+        }
+    });
+    (base_url, received)
+}
+
+#[test]
+fn test_http_fetcher() {
+    let (base_url, requests) = serve(200, "1abc2\npqr3stu8vwx\n");
+    let fetcher = HttpFetcher {
+        base_url,
+        session: Some("cafe".to_string()),
+    };
+    assert_eq!(fetcher.fetch(1).unwrap(), "1abc2\npqr3stu8vwx\n");
+    let request = requests.recv().unwrap();
+    assert!(request.starts_with("GET /2023/day/1/input HTTP/1.1\r\n"));
+    assert!(request.contains("session=cafe"));
+}
+
+#[test]
+fn test_http_fetcher_errors() {
+    let (base_url, _requests) = serve(404, "not unlocked");
+    let fetcher = HttpFetcher {
+        base_url: base_url.clone(),
+        session: Some("cafe".to_string()),
+    };
+    assert!(matches!(
+        fetcher.fetch(25),
+        Err(FetchError::Status { status: 404, .. })
+    ));
+
+    let fetcher = HttpFetcher {
+        base_url: "http://127.0.0.1:9".to_string(),
+        session: None,
+    };
+    assert!(matches!(fetcher.fetch(2), Err(FetchError::MissingSession)));
+}
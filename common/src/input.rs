@@ -0,0 +1,152 @@
+use crate::cache::{self, InputCache};
+use crate::fetch::FetchError;
+use std::fmt;
+use std::io::Read;
+use std::path::PathBuf;
+
+/// Where a puzzle input comes from.
+#[derive(Debug, PartialEq)]
+pub enum Source {
+    Stdin,
+    /// `dayN/input.txt` in the input cache, fetched when missing.
+    Day(u8),
+    Path(PathBuf),
+}
+
+impl Source {
+    /// `-` reads stdin, a bare number is a day and anything else is a path.
+    pub fn from_arg(arg: &str) -> Source {
+        if arg == "-" {
+            return Source::Stdin;
+        }
+        match arg.parse::<u8>() {
+            Ok(day) => Source::Day(day),
+            Err(_) => Source::Path(PathBuf::from(arg)),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Source::Stdin => "<stdin>".to_string(),
+            Source::Day(day) => cache::input_path(&cache::input_dir(), *day)
+                .display()
+                .to_string(),
+            Source::Path(path) => path.display().to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    MissingArgument,
+    Io {
+        name: String,
+        source: std::io::Error,
+    },
+    Fetch {
+        name: String,
+        source: FetchError,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::MissingArgument => write!(f, "no input file provided"),
+            InputError::Io { name, source } => write!(f, "could not read {}: {}", name, source),
+            InputError::Fetch { name, source } => {
+                write!(
+                    f,
+                    "{} is not cached and could not be fetched: {}",
+                    name, source
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::MissingArgument => None,
+            InputError::Io { source, .. } => Some(source),
+            InputError::Fetch { source, .. } => Some(source),
+        }
+    }
+}
+
+pub fn load(source: &Source) -> Result<String, InputError> {
+    let io_error = |source_error| InputError::Io {
+        name: source.name(),
+        source: source_error,
+    };
+    let text = match source {
+        Source::Stdin => {
+            let mut text = String::new();
+            std::io::stdin()
+                .read_to_string(&mut text)
+                .map_err(io_error)?;
+            text
+        }
+        Source::Day(day) => InputCache::from_env().get(*day)?,
+        Source::Path(path) => std::fs::read_to_string(path).map_err(io_error)?,
+    };
+    Ok(normalize(&text))
+}
+
+/// Loads the input named by the first argument after the program name,
+/// along with where it came from.
+pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<(Source, String), InputError> {
+    let arg = args.into_iter().nth(1).ok_or(InputError::MissingArgument)?;
+    let source = Source::from_arg(&arg);
+    let text = load(&source)?;
+    Ok((source, text))
+}
+
+/// [`from_args`] for the day binaries: reports the error and exits instead
+/// of returning it.
+pub fn from_env() -> (Source, String) {
+    from_args(std::env::args()).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    })
+}
+
+/// Turns CRLF line endings into LF and drops trailing newlines, so inputs
+/// saved on any platform parse the same.
+pub fn normalize(text: &str) -> String {
+    text.replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_string()
+}
+
+#[test]
+fn test_from_arg() {
+    assert_eq!(Source::from_arg("-"), Source::Stdin);
+    assert_eq!(Source::from_arg("3"), Source::Day(3));
+    assert_eq!(
+        Source::from_arg("day1/sample.txt"),
+        Source::Path(PathBuf::from("day1/sample.txt"))
+    );
+    assert_eq!(Source::Day(12).name(), "day12/input.txt");
+}
+
+#[test]
+fn test_normalize() {
+    assert_eq!(normalize("1abc2\r\npqr3stu8vwx\r\n"), "1abc2\npqr3stu8vwx");
+    assert_eq!(normalize("a\nb\n\n\n"), "a\nb");
+    assert_eq!(normalize("a\n\nb"), "a\n\nb");
+    assert_eq!(normalize(""), "");
+}
+
+#[test]
+fn test_load_names_missing_file() {
+    let err = load(&Source::from_arg("does/not/exist.txt")).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("could not read does/not/exist.txt: "));
+    assert!(matches!(
+        from_args(vec!["day1-part1".to_string()]),
+        Err(InputError::MissingArgument)
+    ));
+}
@@ -0,0 +1,11 @@
+pub mod answers;
+pub mod cache;
+mod error;
+pub mod fetch;
+pub mod input;
+pub mod samples;
+mod solution;
+
+pub use error::{ParseError, SolveError};
+pub use samples::{Sample, Samples};
+pub use solution::{Solution, Solver};
@@ -0,0 +1,106 @@
+use crate::input::{self, Source};
+use crate::Solver;
+use std::fmt::Display;
+use std::path::PathBuf;
+
+/// An example input from the puzzle text with the answers it gives.
+pub struct Sample {
+    /// Relative to the day's directory.
+    pub file: &'static str,
+    pub part1: Option<&'static dyn Display>,
+    pub part2: Option<&'static dyn Display>,
+}
+
+impl Sample {
+    pub const fn new(file: &'static str) -> Sample {
+        Sample {
+            file,
+            part1: None,
+            part2: None,
+        }
+    }
+
+    pub const fn part1(self, expected: &'static dyn Display) -> Sample {
+        Sample {
+            part1: Some(expected),
+            ..self
+        }
+    }
+
+    pub const fn part2(self, expected: &'static dyn Display) -> Sample {
+        Sample {
+            part2: Some(expected),
+            ..self
+        }
+    }
+
+    pub fn expected(&self, part: u8) -> Option<String> {
+        match part {
+            1 => self.part1.map(|expected| expected.to_string()),
+            2 => self.part2.map(|expected| expected.to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// The samples a day declares with [`samples!`](crate::samples).
+pub trait Samples {
+    const SAMPLES: &'static [Sample];
+}
+
+/// Declares a day's samples and generates a `#[test]` for every sample and
+/// part, named `samples::<name>::<part>`:
+///
+/// ```ignore
+/// common::samples! {
+///     Day1,
+///     sample: "sample.txt" => { part1: 142 },
+///     sample_part2: "sample.part2.txt" => { part2: 281 },
+/// }
+/// ```
+#[macro_export]
+macro_rules! samples {
+    ($day:ident, $($name:ident: $file:literal => { $($part:ident: $expected:expr),+ $(,)? }),* $(,)?) => {
+        impl $crate::Samples for $day {
+            const SAMPLES: &'static [$crate::Sample] = &[
+                $($crate::Sample::new($file)$(.$part(&$expected))+),*
+            ];
+        }
+
+        #[cfg(test)]
+        mod samples {
+            $(
+                mod $name {
+                    $(
+                        #[test]
+                        fn $part() {
+                            $crate::samples::check(
+                                &super::super::$day,
+                                concat!(env!("CARGO_MANIFEST_DIR"), "/", $file),
+                                stringify!($part),
+                                &$expected,
+                            );
+                        }
+                    )+
+                }
+            )*
+        }
+    };
+}
+
+/// The body of a generated sample test.
+pub fn check(solver: &dyn Solver, path: &str, part: &str, expected: &dyn Display) {
+    let source = Source::Path(PathBuf::from(path));
+    let file_content = input::load(&source).unwrap_or_else(|err| panic!("{}", err));
+    let parsed = solver
+        .parse(&file_content)
+        .unwrap_or_else(|err| panic!("{}", err.render(path, &file_content)));
+    let actual = match part {
+        "part1" => Some(solver.part1(parsed.as_ref())),
+        "part2" => solver.part2(parsed.as_ref()),
+        _ => panic!("unknown part {}", part),
+    };
+    let actual =
+        actual.map(|answer| answer.unwrap_or_else(|err| panic!("{} {}: {}", path, part, err)));
+    assert_eq!(actual, Some(expected.to_string()), "{} {}", path, part);
+}
@@ -0,0 +1,117 @@
+use crate::{ParseError, Sample, Samples, SolveError};
+use std::any::Any;
+use std::fmt::Display;
+
+/// A day's puzzle, split into parsing and the two parts so callers can run
+/// (and time) each phase on its own. Its examples are declared with
+/// [`samples!`](crate::samples).
+pub trait Solution: Samples {
+    const DAY: u8;
+    /// Whether [`Solution::part2`] is implemented, for listings that don't
+    /// run it.
+    const SOLVES_PART2: bool = false;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError>;
+
+    /// Days whose second part isn't solved yet keep the default.
+    fn part2(_input: &Self::Input) -> Option<Result<Self::Answer2, SolveError>> {
+        None
+    }
+}
+
+/// Object-safe view of a [`Solution`], so the runner can keep every day in
+/// one list. Answers are rendered with `Display`.
+pub trait Solver {
+    fn day(&self) -> u8;
+
+    fn solves_part2(&self) -> bool;
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    fn part1(&self, input: &dyn Any) -> Result<String, SolveError>;
+
+    fn part2(&self, input: &dyn Any) -> Option<Result<String, SolveError>>;
+
+    fn samples(&self) -> &'static [Sample];
+}
+
+impl<S> Solver for S
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn solves_part2(&self) -> bool {
+        S::SOLVES_PART2
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part1(&self, input: &dyn Any) -> Result<String, SolveError> {
+        S::part1(downcast::<S>(input)).map(|answer| answer.to_string())
+    }
+
+    fn part2(&self, input: &dyn Any) -> Option<Result<String, SolveError>> {
+        S::part2(downcast::<S>(input)).map(|answer| answer.map(|answer| answer.to_string()))
+    }
+
+    fn samples(&self) -> &'static [Sample] {
+        S::SAMPLES
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref::<S::Input>()
+        .expect("input was parsed by a different solution")
+}
+
+#[cfg(test)]
+struct WordCount;
+
+#[cfg(test)]
+impl Samples for WordCount {
+    const SAMPLES: &'static [Sample] = &[Sample::new("words.txt").part1(&3)];
+}
+
+#[cfg(test)]
+impl Solution for WordCount {
+    const DAY: u8 = 0;
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.split_whitespace().map(String::from).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(input.len())
+    }
+}
+
+#[test]
+fn test_solver_erases_solution() {
+    let solver: &dyn Solver = &WordCount;
+    let input = solver.parse("one two three").unwrap();
+    assert_eq!(solver.day(), 0);
+    assert!(!solver.solves_part2());
+    assert_eq!(solver.part1(input.as_ref()), Ok("3".to_string()));
+    assert_eq!(solver.part2(input.as_ref()), None);
+    assert_eq!(solver.samples()[0].expected(1), Some("3".to_string()));
+}
@@ -0,0 +1,474 @@
+use crate::matcher::{Match, Matcher, DIGITS};
+use crate::numerals;
+use crate::vocabulary::Vocabulary;
+use common::{ParseError, SolveError};
+use std::fmt;
+
+/// What counts as a digit of a calibration value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatchRules {
+    /// Only `0` to `9`, as in part 1.
+    Digits,
+    /// Digits and the English words `one` to `nine`, as in part 2.
+    DigitsAndWords,
+    /// Only the English words.
+    WordsOnly,
+    /// The words of another vocabulary, with or without digits.
+    Custom {
+        digits: bool,
+        vocabulary: Vocabulary,
+    },
+    /// The English numerals up to ninety-nine, see [`numerals::compound`],
+    /// with or without digits.
+    Compound { digits: bool },
+}
+
+/// How the first and the last number of a line make its value.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Numbers {
+    /// The first digit of the first number and the last digit of the last,
+    /// so "twenty-one" to "fourteen" is 24.
+    #[default]
+    Digits,
+    /// The first and the last number written one after the other, so
+    /// "twenty-one" to "fourteen" is 2114.
+    Whole,
+}
+
+impl MatchRules {
+    fn matcher(&self) -> Matcher {
+        let (digits, words) = match self {
+            MatchRules::Digits => (true, vec![]),
+            MatchRules::DigitsAndWords => (true, owned(&Vocabulary::english())),
+            MatchRules::WordsOnly => (false, owned(&Vocabulary::english())),
+            MatchRules::Custom { digits, vocabulary } => (*digits, owned(vocabulary)),
+            MatchRules::Compound { digits } => (*digits, numerals::compound()),
+        };
+        let digits = DIGITS.iter().copied().filter(|_| digits);
+        let words = words.iter().map(|(word, value)| (word.as_str(), *value));
+        Matcher::new(digits.chain(words))
+    }
+}
+
+fn owned(vocabulary: &Vocabulary) -> Vec<(String, u32)> {
+    vocabulary
+        .words()
+        .map(|(word, value)| (word.to_string(), value))
+        .collect()
+}
+
+/// What to do with a line without any digit.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Missing {
+    /// Count the line as zero.
+    #[default]
+    Zero,
+    /// Leave the line out.
+    Skip,
+    /// Stop at the line with an error.
+    Error,
+}
+
+/// Reads calibration values under one set of [`MatchRules`].
+#[derive(Debug, Clone)]
+pub struct Calibration {
+    matcher: Matcher,
+    missing: Missing,
+    numbers: Numbers,
+}
+
+/// How many lines of a sum fell into each category.
+#[derive(Debug, Default, PartialEq)]
+pub struct Summary {
+    /// Lines with a first and a last digit.
+    pub counted: usize,
+    /// Counted lines where one digit was both the first and the last.
+    pub single: usize,
+    /// Lines without digits that counted as zero.
+    pub zero: usize,
+    /// Lines without digits that were left out.
+    pub skipped: usize,
+}
+
+impl Summary {
+    /// How many lines were read.
+    pub fn lines(&self) -> usize {
+        self.counted + self.zero + self.skipped
+    }
+
+    /// Adds the counts of `other`.
+    pub fn merge(&mut self, other: &Summary) {
+        self.counted += other.counted;
+        self.single += other.single;
+        self.zero += other.zero;
+        self.skipped += other.skipped;
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} counted ({} with a single digit), {} without digits counted as zero, {} skipped",
+            self.counted, self.single, self.zero, self.skipped
+        )
+    }
+}
+
+/// Why a sum of calibration values failed.
+#[derive(Debug, PartialEq)]
+pub enum SumError {
+    /// A line without digits under [`Missing::Error`].
+    NoDigits(ParseError),
+    /// Adding the value of `line` to the total so far overflowed.
+    Overflow { line: usize, total: u64, value: u64 },
+    /// `line` of a memory-mapped input is not valid UTF-8.
+    InvalidUtf8 { line: usize },
+}
+
+impl fmt::Display for SumError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SumError::NoDigits(err) => write!(f, "{}", err),
+            SumError::Overflow { line, total, value } => write!(
+                f,
+                "sum overflows at line {}: {} + {} does not fit in a u64",
+                line, total, value
+            ),
+            SumError::InvalidUtf8 { line } => write!(f, "line {} is not valid UTF-8", line),
+        }
+    }
+}
+
+impl std::error::Error for SumError {}
+
+impl SumError {
+    /// Prints the error, with the offending line of `source` (named `name`)
+    /// when there is one, and exits.
+    pub fn exit(&self, name: &str, source: &str) -> ! {
+        match self {
+            SumError::NoDigits(err) => err.exit(name, source),
+            SumError::Overflow { .. } | SumError::InvalidUtf8 { .. } => {
+                eprintln!("error: {}", self);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// The sum of the calibration values of `lines`, counting lines without
+/// digits as zero.
+pub fn calibrate(lines: &[String], rules: &MatchRules) -> Result<u64, SumError> {
+    Calibration::new(rules)
+        .sum(lines)
+        .map(|(total_sum, _)| total_sum)
+}
+
+impl From<SumError> for SolveError {
+    fn from(err: SumError) -> SolveError {
+        SolveError::new(err.to_string())
+    }
+}
+
+impl Calibration {
+    pub fn new(rules: &MatchRules) -> Calibration {
+        Calibration {
+            matcher: rules.matcher(),
+            missing: Missing::default(),
+            numbers: Numbers::default(),
+        }
+    }
+
+    pub fn numbers(mut self, numbers: Numbers) -> Calibration {
+        self.numbers = numbers;
+        self
+    }
+
+    pub fn on_missing(mut self, missing: Missing) -> Calibration {
+        self.missing = missing;
+        self
+    }
+
+    /// The tokens of the first and the last digit of `line`.
+    pub fn ends(&self, line: &str) -> Option<(Match, Match)> {
+        self.matcher.ends(line)
+    }
+
+    /// The value of one line, if it has any digit.
+    pub fn value(&self, line: &str) -> Option<u32> {
+        let first_last = first_and_last_number(line, self)?;
+        Some(first_last_to_num(first_last, self.numbers))
+    }
+
+    /// Sums the values of `lines`, treating lines without digits as the
+    /// [`Missing`] policy says.
+    pub fn sum<S: AsRef<str>>(
+        &self,
+        lines: impl IntoIterator<Item = S>,
+    ) -> Result<(u64, Summary), SumError> {
+        self.sum_with(lines, |_, _, _| ())
+    }
+
+    /// [`Calibration::sum`], calling `on_line` with each line's number, its
+    /// value if it has one and the total so far.
+    pub fn sum_with<S: AsRef<str>>(
+        &self,
+        lines: impl IntoIterator<Item = S>,
+        mut on_line: impl FnMut(usize, Option<u32>, u64),
+    ) -> Result<(u64, Summary), SumError> {
+        let mut total_sum: u64 = 0;
+        let mut summary = Summary::default();
+
+        for (i, line) in lines.into_iter().enumerate() {
+            let line = line.as_ref();
+            let mut value = None;
+            match self.ends(line) {
+                Some((first, last)) => {
+                    summary.counted += 1;
+                    if first == last {
+                        summary.single += 1;
+                    }
+                    let first_last = FirstLast {
+                        first: first.value,
+                        last: last.value,
+                    };
+                    let line_value = first_last_to_num(first_last, self.numbers);
+                    total_sum = accumulate(total_sum, line_value as u64, i + 1)?;
+                    value = Some(line_value);
+                }
+                None => match self.missing {
+                    Missing::Zero => summary.zero += 1,
+                    Missing::Skip => summary.skipped += 1,
+                    Missing::Error => {
+                        let err = ParseError::at(line, line, "line has no digits");
+                        return Err(SumError::NoDigits(err.on_line(i + 1)));
+                    }
+                },
+            }
+            on_line(i + 1, value, total_sum);
+        }
+
+        Ok((total_sum, summary))
+    }
+}
+
+/// Adds `value` to `total`, failing at `line` when it overflows.
+pub(crate) fn accumulate(total: u64, value: u64, line: usize) -> Result<u64, SumError> {
+    total
+        .checked_add(value)
+        .ok_or(SumError::Overflow { line, total, value })
+}
+
+#[derive(Debug, PartialEq)]
+struct FirstLast {
+    first: u32,
+    last: u32,
+}
+
+fn first_and_last_number(line: &str, calibration: &Calibration) -> Option<FirstLast> {
+    let (first, last) = calibration.ends(line)?;
+    Some(FirstLast {
+        first: first.value,
+        last: last.value,
+    })
+}
+
+#[test]
+fn test_first_and_last_number() {
+    let english = Calibration::new(&MatchRules::DigitsAndWords);
+    assert_eq!(
+        first_and_last_number("two1nine", &english),
+        Some(FirstLast { first: 2, last: 9 })
+    );
+    assert_eq!(
+        first_and_last_number("eightwothree", &english),
+        Some(FirstLast { first: 8, last: 3 })
+    );
+    assert_eq!(
+        first_and_last_number("abcone2threexyz", &english),
+        Some(FirstLast { first: 1, last: 3 })
+    );
+    assert_eq!(
+        first_and_last_number("xtwone3four", &english),
+        Some(FirstLast { first: 2, last: 4 })
+    );
+    assert_eq!(
+        first_and_last_number("4nineeightseven2", &english),
+        Some(FirstLast { first: 4, last: 2 })
+    );
+    assert_eq!(
+        first_and_last_number("zoneight234", &english),
+        Some(FirstLast { first: 1, last: 4 })
+    );
+    assert_eq!(
+        first_and_last_number("7pqrstsixteen", &english),
+        Some(FirstLast { first: 7, last: 6 })
+    );
+    assert_eq!(
+        first_and_last_number("mtqxjrcn1two9fourncghmnbsseight", &english),
+        Some(FirstLast { first: 1, last: 8 })
+    );
+    assert_eq!(
+        first_and_last_number("ønskeone3tværs", &english),
+        Some(FirstLast { first: 1, last: 3 })
+    );
+    assert_eq!(
+        first_and_last_number("sixæfiveå", &english),
+        Some(FirstLast { first: 6, last: 5 })
+    );
+    assert_eq!(first_and_last_number("æøå", &english), None);
+
+    let german = Calibration::new(&MatchRules::Custom {
+        digits: true,
+        vocabulary: Vocabulary::language("de").unwrap(),
+    });
+    assert_eq!(
+        first_and_last_number("xfünfacht3zweiundsechzig", &german),
+        Some(FirstLast { first: 5, last: 2 })
+    );
+}
+
+#[test]
+fn test_first_and_last_number_long_line() {
+    let line = format!("two{}nine", "x".repeat(1_000_000));
+    assert_eq!(
+        first_and_last_number(&line, &Calibration::new(&MatchRules::DigitsAndWords)),
+        Some(FirstLast { first: 2, last: 9 })
+    );
+}
+
+#[test]
+fn test_match_rules() {
+    let line = "a1two3fourb";
+    let value = |rules| Calibration::new(&rules).value(line).unwrap_or(0);
+    assert_eq!(value(MatchRules::Digits), 13);
+    assert_eq!(value(MatchRules::DigitsAndWords), 14);
+    assert_eq!(value(MatchRules::WordsOnly), 24);
+    let danish = Vocabulary::language("da").unwrap();
+    assert_eq!(
+        value(MatchRules::Custom {
+            digits: false,
+            vocabulary: danish.clone(),
+        }),
+        0
+    );
+    assert_eq!(
+        Calibration::new(&MatchRules::Custom {
+            digits: false,
+            vocabulary: danish,
+        })
+        .value("1to3fire"),
+        Some(24)
+    );
+}
+
+#[test]
+fn test_compound() {
+    let compound = Calibration::new(&MatchRules::Compound { digits: true });
+    let whole = compound.clone().numbers(Numbers::Whole);
+    let line = "xtwenty-one3fourteeny";
+    assert_eq!(compound.value(line), Some(24));
+    assert_eq!(whole.value(line), Some(2114));
+    assert_eq!(whole.value("seventy-two"), Some(7272));
+    assert_eq!(whole.value("eightwo"), Some(82));
+    assert_eq!(whole.value("sixty5"), Some(605));
+    assert_eq!(compound.value("sixty5"), Some(65));
+    assert_eq!(compound.value("eighteen"), Some(18));
+    assert_eq!(whole.value("eighteen"), Some(1818));
+    assert_eq!(whole.value("nine-one"), Some(91));
+
+    let words = Calibration::new(&MatchRules::Compound { digits: false });
+    assert_eq!(words.value("1ten2"), Some(10));
+    assert_eq!(words.numbers(Numbers::Whole).value("1ten2"), Some(1010));
+}
+
+#[test]
+fn test_missing() {
+    let lines = ["a0b", "x", "05", "7", "", "1y0"].map(String::from);
+    let digits = Calibration::new(&MatchRules::Digits);
+    assert_eq!(digits.value("0abc5"), Some(5));
+    assert_eq!(digits.value("a0b"), Some(0));
+    assert_eq!(digits.value("x"), None);
+
+    assert_eq!(
+        digits.clone().sum(&lines),
+        Ok((
+            92,
+            Summary {
+                counted: 4,
+                single: 2,
+                zero: 2,
+                skipped: 0,
+            }
+        ))
+    );
+    assert_eq!(
+        digits.clone().on_missing(Missing::Skip).sum(&lines),
+        Ok((
+            92,
+            Summary {
+                counted: 4,
+                single: 2,
+                zero: 0,
+                skipped: 2,
+            }
+        ))
+    );
+    assert_eq!(
+        digits.on_missing(Missing::Error).sum(&lines),
+        Err(SumError::NoDigits(ParseError {
+            line: 2,
+            column: 1,
+            text: "x".to_string(),
+            message: "line has no digits".to_string(),
+        }))
+    );
+}
+
+#[test]
+fn test_sum_with() {
+    let lines = ["a1b2", "x", "77"].map(String::from);
+    let mut seen = vec![];
+    let calibration = Calibration::new(&MatchRules::Digits).on_missing(Missing::Skip);
+    let (total_sum, _) = calibration
+        .sum_with(&lines, |line, value, total| seen.push((line, value, total)))
+        .unwrap();
+    assert_eq!(total_sum, 89);
+    assert_eq!(
+        seen,
+        vec![(1, Some(12), 12), (2, None, 12), (3, Some(77), 89)]
+    );
+}
+
+#[test]
+fn test_accumulate() {
+    assert_eq!(accumulate(u32::MAX as u64, 99, 1), Ok(u32::MAX as u64 + 99));
+    assert_eq!(accumulate(u64::MAX - 99, 99, 2), Ok(u64::MAX));
+    assert_eq!(
+        accumulate(u64::MAX - 10, 99, 3),
+        Err(SumError::Overflow {
+            line: 3,
+            total: u64::MAX - 10,
+            value: 99,
+        })
+    );
+}
+
+fn first_last_to_num(first_last: FirstLast, numbers: Numbers) -> u32 {
+    let FirstLast { mut first, last } = first_last;
+    match numbers {
+        Numbers::Digits => {
+            while first >= 10 {
+                first /= 10;
+            }
+            first * 10 + last % 10
+        }
+        Numbers::Whole => {
+            let mut shift = 10;
+            while shift <= last {
+                shift *= 10;
+            }
+            first * shift + last
+        }
+    }
+}
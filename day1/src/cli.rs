@@ -0,0 +1,309 @@
+use crate::calibration::{Calibration, MatchRules, Missing, Numbers};
+use crate::explain::{self, Style};
+use crate::parallel::{self, Mapped};
+use crate::vocabulary::Vocabulary;
+use crate::Day1;
+use common::cache::{self, InputCache};
+use common::input::{self, Source};
+use common::Solution;
+use std::io::IsTerminal;
+
+pub const USAGE: &str = "\
+usage: day1-part1|day1-part2 [options] <input>
+
+<input> is a path, - for stdin or a day number.
+
+options:
+  --match <tokens>      what counts as a digit: digits (part 1), words, or both (part 2)
+  --lang <code>         number words of a built-in language: en (default), de, da, fr
+  --vocabulary <file>   number words from a file of 'word = digit' lines
+  --compound            words are English numerals up to ninety-nine, like twenty-one
+  --whole               join the first and last number whole instead of their digits
+  --missing <policy>    lines without digits: zero (default), skip or error
+  --per-line            print each line's value and the running total
+  --parallel            map the input file into memory and sum it on all cores
+  --threads <count>     --parallel with this many threads
+  --explain             show the tokens each line's value comes from
+  --disagree            only explain lines where part 1 reads another value";
+
+/// Command-line options of the day 1 binaries.
+#[derive(Debug)]
+pub struct Options {
+    pub input: Source,
+    pub rules: MatchRules,
+    pub numbers: Numbers,
+    pub missing: Missing,
+    pub per_line: bool,
+    /// How many threads sum a memory-mapped input, if it is summed that way.
+    pub threads: Option<usize>,
+    pub explain: Option<Explain>,
+}
+
+/// Which lines `--explain` prints.
+#[derive(Debug, PartialEq)]
+pub enum Explain {
+    All,
+    Disagreeing,
+}
+
+/// Parses the arguments of a binary whose rules are `default` unless
+/// `--match`, `--lang` or `--vocabulary` say otherwise.
+pub fn parse(args: &[String], default: MatchRules) -> Result<Options, String> {
+    let mut input = None;
+    let mut tokens = None;
+    let mut vocabulary = None;
+    let mut compound = false;
+    let mut numbers = Numbers::default();
+    let mut missing = Missing::default();
+    let mut per_line = false;
+    let mut threads = None;
+    let mut explain = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--match" => tokens = Some(value()?.as_str()),
+            "--lang" | "--vocabulary" if vocabulary.is_some() => {
+                return Err("only one of --lang and --vocabulary may be given".to_string())
+            }
+            "--lang" => {
+                let code = value()?;
+                vocabulary = Some(Vocabulary::language(code).map_err(|err| err.to_string())?);
+            }
+            "--vocabulary" => {
+                let path = value()?;
+                vocabulary = Some(Vocabulary::load(path).map_err(|err| err.to_string())?);
+            }
+            "--compound" => compound = true,
+            "--whole" => numbers = Numbers::Whole,
+            "--missing" => {
+                missing = match value()?.as_str() {
+                    "zero" => Missing::Zero,
+                    "skip" => Missing::Skip,
+                    "error" => Missing::Error,
+                    policy => return Err(format!("unknown --missing {}", policy)),
+                }
+            }
+            "--per-line" => per_line = true,
+            "--parallel" => {
+                threads.get_or_insert_with(|| {
+                    std::thread::available_parallelism().map_or(1, |count| count.get())
+                });
+            }
+            "--threads" => {
+                let count = value()?;
+                threads = match count.parse::<usize>() {
+                    Ok(count) if count > 0 => Some(count),
+                    _ => return Err(format!("invalid thread count {}", count)),
+                };
+            }
+            "--explain" => {
+                explain.get_or_insert(Explain::All);
+            }
+            "--disagree" => explain = Some(Explain::Disagreeing),
+            flag if flag.starts_with("--") => return Err(format!("unknown option {}", flag)),
+            _ if input.is_some() => return Err(format!("unexpected argument {}", arg)),
+            _ => input = Some(Source::from_arg(arg)),
+        }
+    }
+
+    let (digits, words) = match tokens {
+        None => match default {
+            MatchRules::Digits => (true, false),
+            MatchRules::WordsOnly => (false, true),
+            _ => (true, true),
+        },
+        Some("digits") => (true, false),
+        Some("words") => (false, true),
+        Some("both") => (true, true),
+        Some(tokens) => return Err(format!("unknown --match {}", tokens)),
+    };
+    if compound && vocabulary.is_some() {
+        return Err("--compound can't be combined with --lang or --vocabulary".to_string());
+    }
+    let rules = match (digits, words, vocabulary) {
+        _ if compound && !words => return Err("--compound needs words to match".to_string()),
+        (_, false, Some(_)) => {
+            return Err("--lang and --vocabulary need words to match".to_string())
+        }
+        _ if compound => MatchRules::Compound { digits },
+        (digits, true, Some(vocabulary)) => MatchRules::Custom { digits, vocabulary },
+        (true, false, None) => MatchRules::Digits,
+        (true, true, None) => MatchRules::DigitsAndWords,
+        (false, _, None) => MatchRules::WordsOnly,
+    };
+
+    let input = input.ok_or("missing input argument")?;
+    if threads.is_some() {
+        if per_line || explain.is_some() {
+            return Err("--parallel can't be combined with --per-line or --explain".to_string());
+        }
+        if input == Source::Stdin {
+            return Err("--parallel needs an input file".to_string());
+        }
+    }
+
+    Ok(Options {
+        input,
+        rules,
+        numbers,
+        missing,
+        per_line,
+        threads,
+        explain,
+    })
+}
+
+/// [`parse`] on the process arguments: prints the usage and exits on error,
+/// and warns about vocabulary words that overlap.
+pub fn from_env(default: MatchRules) -> Options {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let options = parse(&args, default).unwrap_or_else(|message| {
+        eprintln!("error: {}\n\n{}", message, USAGE);
+        std::process::exit(2);
+    });
+    let MatchRules::Custom { vocabulary, .. } = &options.rules else {
+        return options;
+    };
+    for overlap in vocabulary.overlaps() {
+        eprintln!(
+            "warning: '{}' contains '{}', the longest word wins where both match",
+            overlap.word, overlap.contains
+        );
+    }
+    options
+}
+
+/// The `main` of both day 1 binaries, which only differ in their default
+/// rules.
+pub fn main(default: MatchRules) {
+    let options = from_env(default);
+    let calibration = Calibration::new(&options.rules)
+        .numbers(options.numbers)
+        .on_missing(options.missing);
+    if let Some(threads) = options.threads {
+        return main_parallel(&options.input, &calibration, threads);
+    }
+
+    let file_content = input::load(&options.input).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    });
+
+    let lines = Day1::parse(&file_content)
+        .unwrap_or_else(|err| err.exit(&options.input.name(), &file_content));
+    if let Some(which) = &options.explain {
+        let part1 = Calibration::new(&MatchRules::Digits);
+        let style = match std::io::stdout().is_terminal() {
+            true => Style::Ansi,
+            false => Style::Brackets,
+        };
+        for (i, line) in lines.iter().enumerate() {
+            let explanation = explain::explain(line, &calibration, &part1);
+            if *which == Explain::All || explanation.disagrees() {
+                println!("{:>5}: {}", i + 1, explanation.render(style));
+            }
+        }
+    }
+    let (total_sum, summary) = calibration
+        .sum_with(&lines, |line, value, total| {
+            if !options.per_line {
+                return;
+            }
+            match value {
+                Some(value) => println!("{:>5}: {:>4}  total {}", line, value, total),
+                None => println!("{:>5}:    -  total {}", line, total),
+            }
+        })
+        .unwrap_or_else(|err| err.exit(&options.input.name(), &file_content));
+    println!("Total sum: {}", total_sum);
+    println!("Lines: {}", summary);
+}
+
+fn main_parallel(source: &Source, calibration: &Calibration, threads: usize) {
+    let exit = |message: String| -> ! {
+        eprintln!("error: {}", message);
+        std::process::exit(1);
+    };
+    let path = match source {
+        Source::Path(path) => path.clone(),
+        Source::Day(day) => {
+            // Makes sure the input is cached before mapping the cached file.
+            if let Err(err) = InputCache::from_env().get(*day) {
+                exit(err.to_string());
+            }
+            cache::input_path(&cache::input_dir(), *day)
+        }
+        Source::Stdin => unreachable!("parse rejects --parallel on stdin"),
+    };
+    let mapped =
+        Mapped::open(&path).unwrap_or_else(|err| exit(format!("{}: {}", path.display(), err)));
+    let bytes = mapped.bytes();
+
+    let (total_sum, summary) = parallel::sum(bytes, calibration, threads).unwrap_or_else(|err| {
+        err.exit(&path.display().to_string(), &String::from_utf8_lossy(bytes))
+    });
+    println!("Total sum: {}", total_sum);
+    println!("Lines: {}", summary);
+}
+
+#[test]
+fn test_parse() {
+    let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+    let part2 = |args_: &[&str]| parse(&args(args_), MatchRules::DigitsAndWords);
+
+    let options = part2(&["1"]).unwrap();
+    assert_eq!(options.input, Source::Day(1));
+    assert_eq!(options.rules, MatchRules::DigitsAndWords);
+    assert_eq!(options.missing, Missing::Zero);
+    assert!(!options.per_line);
+    assert_eq!(options.explain, None);
+
+    let options = part2(&["--missing", "skip", "--per-line", "1"]).unwrap();
+    assert_eq!(options.missing, Missing::Skip);
+    assert!(options.per_line);
+    assert_eq!(options.threads, None);
+
+    let options = part2(&["--threads", "3", "input.txt"]).unwrap();
+    assert_eq!(options.threads, Some(3));
+    assert!(part2(&["--parallel", "1"]).unwrap().threads.is_some());
+
+    let options = parse(&args(&["1"]), MatchRules::Digits).unwrap();
+    assert_eq!(options.rules, MatchRules::Digits);
+
+    let options = part2(&["--lang", "da", "-"]).unwrap();
+    assert_eq!(options.input, Source::Stdin);
+    assert_eq!(
+        options.rules,
+        MatchRules::Custom {
+            digits: true,
+            vocabulary: Vocabulary::language("da").unwrap(),
+        }
+    );
+
+    let options = part2(&["--match", "words", "1"]).unwrap();
+    assert_eq!(options.rules, MatchRules::WordsOnly);
+
+    let options = part2(&["--compound", "--whole", "1"]).unwrap();
+    assert_eq!(options.rules, MatchRules::Compound { digits: true });
+    assert_eq!(options.numbers, Numbers::Whole);
+
+    let options = part2(&["--disagree", "--explain", "1"]).unwrap();
+    assert_eq!(options.explain, Some(Explain::Disagreeing));
+
+    assert!(part2(&[]).is_err());
+    assert!(part2(&["--lang", "xx", "1"]).is_err());
+    assert!(part2(&["--lang", "de", "--lang", "fr", "1"]).is_err());
+    assert!(part2(&["--vocabulary", "missing.txt", "1"]).is_err());
+    assert!(part2(&["--match", "digits", "--lang", "de", "1"]).is_err());
+    assert!(part2(&["--match", "all", "1"]).is_err());
+    assert!(part2(&["--compound", "--lang", "de", "1"]).is_err());
+    assert!(part2(&["--compound", "--match", "digits", "1"]).is_err());
+    assert!(part2(&["--missing", "none", "1"]).is_err());
+    assert!(part2(&["--threads", "0", "1"]).is_err());
+    assert!(part2(&["--parallel", "-"]).is_err());
+    assert!(part2(&["--parallel", "--explain", "1"]).is_err());
+    assert!(part2(&["1", "2"]).is_err());
+    assert!(part2(&["--lang"]).is_err());
+    assert!(part2(&["--verbose", "1"]).is_err());
+}
@@ -0,0 +1,150 @@
+use crate::calibration::Calibration;
+use crate::matcher::Match;
+
+/// How the first and last token of a line are marked.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Style {
+    /// `[first]` and `<last>`.
+    Brackets,
+    /// The first token in green, the last in cyan and any overlap in yellow.
+    Ansi,
+}
+
+/// How one line was read, next to how part 1 reads it.
+#[derive(Debug, PartialEq)]
+pub struct Explanation<'a> {
+    pub line: &'a str,
+    pub ends: Option<(Match, Match)>,
+    pub value: Option<u32>,
+    pub part1: Option<u32>,
+}
+
+pub fn explain<'a>(
+    line: &'a str,
+    calibration: &Calibration,
+    part1: &Calibration,
+) -> Explanation<'a> {
+    Explanation {
+        line,
+        ends: calibration.ends(line),
+        value: calibration.value(line),
+        part1: part1.value(line),
+    }
+}
+
+impl Explanation<'_> {
+    pub fn disagrees(&self) -> bool {
+        self.value != self.part1
+    }
+
+    /// The line with its tokens marked, followed by their byte offsets and
+    /// the value they make.
+    pub fn render(&self, style: Style) -> String {
+        let (Some((first, last)), Some(value)) = (self.ends, self.value) else {
+            return format!("{}  no digits", self.line);
+        };
+        let mut rendered = format!(
+            "{}  first '{}' at {}..{}, last '{}' at {}..{} => {}",
+            self.highlight(first, last, style),
+            &self.line[first.start..first.end],
+            first.start,
+            first.end,
+            &self.line[last.start..last.end],
+            last.start,
+            last.end,
+            value
+        );
+        if self.disagrees() {
+            match self.part1 {
+                Some(part1) => rendered += &format!(" (part 1: {})", part1),
+                None => rendered += " (part 1: no digits)",
+            }
+        }
+        rendered
+    }
+
+    fn highlight(&self, first: Match, last: Match, style: Style) -> String {
+        let mut cuts = vec![
+            0,
+            first.start,
+            first.end,
+            last.start,
+            last.end,
+            self.line.len(),
+        ];
+        cuts.sort();
+        cuts.dedup();
+
+        let mut highlighted = String::new();
+        for cut in cuts.windows(2) {
+            let (start, end) = (cut[0], cut[1]);
+            let in_first = first.start <= start && end <= first.end;
+            let in_last = last.start <= start && end <= last.end;
+            let segment = &self.line[start..end];
+            match style {
+                Style::Brackets => {
+                    if in_first && start == first.start {
+                        highlighted.push('[');
+                    }
+                    if in_last && start == last.start && last != first {
+                        highlighted.push('<');
+                    }
+                    highlighted.push_str(segment);
+                    if in_last && end == last.end && last != first {
+                        highlighted.push('>');
+                    }
+                    if in_first && end == first.end {
+                        highlighted.push(']');
+                    }
+                }
+                Style::Ansi => {
+                    let colour = match (in_first, in_last) {
+                        (true, true) if first != last => "\x1b[33m",
+                        (true, _) => "\x1b[32m",
+                        (false, true) => "\x1b[36m",
+                        (false, false) => "",
+                    };
+                    if colour.is_empty() {
+                        highlighted.push_str(segment);
+                    } else {
+                        highlighted += &format!("{}{}\x1b[0m", colour, segment);
+                    }
+                }
+            }
+        }
+        highlighted
+    }
+}
+
+#[test]
+fn test_render() {
+    use crate::calibration::MatchRules;
+
+    let part1 = Calibration::new(&MatchRules::Digits);
+    let part2 = Calibration::new(&MatchRules::DigitsAndWords);
+    let explain = |line| explain(line, &part2, &part1);
+    let render = |line| explain(line).render(Style::Brackets);
+
+    assert_eq!(
+        render("two1nine"),
+        "[two]1<nine>  first 'two' at 0..3, last 'nine' at 4..8 => 29 (part 1: 11)"
+    );
+    assert_eq!(
+        render("a7b"),
+        "a[7]b  first '7' at 1..2, last '7' at 1..2 => 77"
+    );
+    assert_eq!(
+        render("ø1twone"),
+        "ø[1]tw<one>  first '1' at 2..3, last 'one' at 5..8 => 11"
+    );
+    assert_eq!(
+        render("twone"),
+        "[tw<o]ne>  first 'two' at 0..3, last 'one' at 2..5 => 21 (part 1: no digits)"
+    );
+    assert_eq!(render("xyz"), "xyz  no digits");
+
+    let ansi = explain("eightwo").render(Style::Ansi);
+    assert!(ansi.starts_with("\x1b[32meigh\x1b[0m\x1b[33mt\x1b[0m\x1b[36mwo\x1b[0m"));
+    assert!(explain("eightwo").disagrees());
+    assert!(!explain("12").disagrees());
+}
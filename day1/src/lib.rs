@@ -0,0 +1,41 @@
+use calibration::{calibrate, MatchRules};
+use common::{ParseError, Solution, SolveError};
+
+pub mod calibration;
+pub mod cli;
+pub mod explain;
+pub mod matcher;
+pub mod numerals;
+pub mod parallel;
+#[cfg(test)]
+mod properties;
+pub mod vocabulary;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const SOLVES_PART2: bool = true;
+
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        calibrate(lines, &MatchRules::Digits).map_err(SolveError::from)
+    }
+
+    fn part2(lines: &Self::Input) -> Option<Result<Self::Answer2, SolveError>> {
+        Some(calibrate(lines, &MatchRules::DigitsAndWords).map_err(SolveError::from))
+    }
+}
+
+common::samples! {
+    Day1,
+    sample: "sample.txt" => { part1: 142, part2: 142 },
+    sample_part2: "sample.part2.txt" => { part2: 281 },
+}
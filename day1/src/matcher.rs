@@ -0,0 +1,203 @@
+use std::cmp::Reverse;
+
+/// The ASCII digits as patterns, to match them in the same scan as words.
+pub const DIGITS: &[(&str, u32)] = &[
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+const NONE: u32 = u32::MAX;
+
+/// An Aho–Corasick automaton over the bytes of a set of patterns, each with a
+/// value. It finds every occurrence of every pattern in one pass over a text,
+/// including occurrences that overlap, like the "eight" and "two" of
+/// "eightwo".
+#[derive(Debug, Clone)]
+pub struct Matcher {
+    /// The state after reading a byte in a state, failure links included.
+    next: Vec<[u32; 256]>,
+    /// The `(length, value)` of each pattern that ends in a state.
+    outputs: Vec<Vec<(usize, u32)>>,
+}
+
+/// One occurrence of a pattern, `start..end` being its byte range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+impl Matcher {
+    pub fn new<'a>(patterns: impl IntoIterator<Item = (&'a str, u32)>) -> Matcher {
+        let mut matcher = Matcher {
+            next: vec![[NONE; 256]],
+            outputs: vec![vec![]],
+        };
+        for (pattern, value) in patterns {
+            let mut state = 0;
+            for &byte in pattern.as_bytes() {
+                if matcher.next[state][byte as usize] == NONE {
+                    matcher.next[state][byte as usize] = matcher.next.len() as u32;
+                    matcher.next.push([NONE; 256]);
+                    matcher.outputs.push(vec![]);
+                }
+                state = matcher.next[state][byte as usize] as usize;
+            }
+            matcher.outputs[state].push((pattern.len(), value));
+        }
+        matcher.link();
+        matcher
+    }
+
+    /// Turns the trie into a complete automaton: breadth first, so a state's
+    /// failure state is always finished before the state itself, missing
+    /// transitions take the failure state's transition and outputs inherit
+    /// the failure state's outputs.
+    fn link(&mut self) {
+        let mut fail = vec![0; self.next.len()];
+        let mut queue = std::collections::VecDeque::from([0]);
+        while let Some(state) = queue.pop_front() {
+            for byte in 0..256 {
+                let child = self.next[state][byte];
+                let fallback = match state {
+                    0 => 0,
+                    _ => self.next[fail[state]][byte],
+                };
+                if child == NONE {
+                    self.next[state][byte] = fallback;
+                    continue;
+                }
+                let child = child as usize;
+                fail[child] = fallback as usize;
+                let inherited = self.outputs[fallback as usize].clone();
+                self.outputs[child].extend(inherited);
+                queue.push_back(child);
+            }
+        }
+    }
+
+    /// The first and the last match in `text`, by where they start. Matches
+    /// that lie inside a longer match don't count, so "eighteen" is not also
+    /// an "eight" but "eightwo" is both an "eight" and a "two".
+    pub fn ends(&self, text: &str) -> Option<(Match, Match)> {
+        let mut ends: Option<(Match, Match)> = None;
+        for found in self.find_iter(text) {
+            let (first, last) = ends.get_or_insert((found, found));
+            if (found.start, Reverse(found.end)) < (first.start, Reverse(first.end)) {
+                *first = found;
+            }
+            // A match ending later either starts later or contains `last`.
+            if (found.end, Reverse(found.start)) > (last.end, Reverse(last.start)) {
+                *last = found;
+            }
+        }
+        ends
+    }
+
+    /// Every match in `text`, ordered by where they end.
+    pub fn find_iter<'m, 't>(&'m self, text: &'t str) -> FindIter<'m, 't> {
+        FindIter {
+            matcher: self,
+            text: text.as_bytes(),
+            position: 0,
+            state: 0,
+            output: 0,
+        }
+    }
+}
+
+pub struct FindIter<'m, 't> {
+    matcher: &'m Matcher,
+    text: &'t [u8],
+    /// How many bytes of `text` have been read.
+    position: usize,
+    state: usize,
+    /// The next output of `state` to report.
+    output: usize,
+}
+
+impl Iterator for FindIter<'_, '_> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        loop {
+            if let Some(&(len, value)) = self.matcher.outputs[self.state].get(self.output) {
+                self.output += 1;
+                return Some(Match {
+                    start: self.position - len,
+                    end: self.position,
+                    value,
+                });
+            }
+            let &byte = self.text.get(self.position)?;
+            self.state = self.matcher.next[self.state][byte as usize] as usize;
+            self.position += 1;
+            self.output = 0;
+        }
+    }
+}
+
+#[test]
+fn test_find_iter() {
+    let matcher =
+        Matcher::new(
+            DIGITS
+                .iter()
+                .copied()
+                .chain([("one", 1), ("two", 2), ("eight", 8)]),
+        );
+    let found = |text| {
+        matcher
+            .find_iter(text)
+            .map(|found: Match| (found.start, found.value))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(found("eightwo"), vec![(0, 8), (4, 2)]);
+    assert_eq!(found("xtwone3"), vec![(1, 2), (3, 1), (6, 3)]);
+    assert_eq!(found("ttwo"), vec![(1, 2)]);
+    assert_eq!(found("øne1ønetwo"), vec![(4, 1), (9, 2)]);
+    assert_eq!(found(""), vec![]);
+
+    let nested = Matcher::new([("ein", 1), ("eins", 1), ("einsam", 7), ("sam", 3)]);
+    assert_eq!(
+        nested.find_iter("einsam").collect::<Vec<_>>(),
+        vec![
+            Match {
+                start: 0,
+                end: 3,
+                value: 1
+            },
+            Match {
+                start: 0,
+                end: 4,
+                value: 1
+            },
+            Match {
+                start: 0,
+                end: 6,
+                value: 7
+            },
+            Match {
+                start: 3,
+                end: 6,
+                value: 3
+            },
+        ]
+    );
+    let (first, last) = nested.ends("einsam").unwrap();
+    assert_eq!((first.end, first.value), (6, 7));
+    assert_eq!((last.start, last.value), (0, 7));
+    let (first, last) = nested.ends("einsamein").unwrap();
+    assert_eq!((first.end, first.value), (6, 7));
+    assert_eq!((last.start, last.value), (6, 1));
+    assert_eq!(nested.ends("xyz"), None);
+}
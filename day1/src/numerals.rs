@@ -0,0 +1,59 @@
+const UNITS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+const TEENS: [&str; 10] = [
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// The English numerals from one to ninety-nine with their values: the
+/// units, the teens, the tens and the hyphenated forms like "twenty-one".
+pub fn compound() -> Vec<(String, u32)> {
+    let mut numerals = vec![];
+    for (i, unit) in UNITS.iter().enumerate() {
+        numerals.push((unit.to_string(), i as u32 + 1));
+    }
+    for (i, teen) in TEENS.iter().enumerate() {
+        numerals.push((teen.to_string(), i as u32 + 10));
+    }
+    for (i, ten) in TENS.iter().enumerate() {
+        let tens = (i as u32 + 2) * 10;
+        numerals.push((ten.to_string(), tens));
+        for (j, unit) in UNITS.iter().enumerate() {
+            numerals.push((format!("{}-{}", ten, unit), tens + j as u32 + 1));
+        }
+    }
+    numerals
+}
+
+#[test]
+fn test_compound() {
+    let numerals = compound();
+    let value = |word: &str| {
+        numerals
+            .iter()
+            .find(|(numeral, _)| numeral == word)
+            .map(|(_, value)| *value)
+    };
+    assert_eq!(numerals.len(), 99);
+    assert_eq!(value("seven"), Some(7));
+    assert_eq!(value("ten"), Some(10));
+    assert_eq!(value("fourteen"), Some(14));
+    assert_eq!(value("forty"), Some(40));
+    assert_eq!(value("twenty-one"), Some(21));
+    assert_eq!(value("ninety-nine"), Some(99));
+    assert_eq!(value("twentyone"), None);
+}
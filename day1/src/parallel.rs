@@ -0,0 +1,192 @@
+use crate::calibration::{accumulate, Calibration, SumError, Summary};
+use memmap2::Mmap;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+/// A file mapped into memory, so huge inputs are read as the threads need
+/// them rather than all up front.
+pub struct Mapped {
+    mmap: Mmap,
+}
+
+impl Mapped {
+    pub fn open(path: &Path) -> io::Result<Mapped> {
+        let file = File::open(path)?;
+        // SAFETY: the map is only read, and inputs are not expected to change
+        // while they are being solved.
+        let mmap = unsafe { Mmap::map(&file)? };
+        Ok(Mapped { mmap })
+    }
+
+    /// The file without trailing line breaks, like
+    /// [`common::input::normalize`]. It is not checked to be UTF-8 here, so
+    /// no page is read until a thread needs it.
+    pub fn bytes(&self) -> &[u8] {
+        let end = self
+            .mmap
+            .iter()
+            .rposition(|&byte| byte != b'\r' && byte != b'\n')
+            .map_or(0, |last| last + 1);
+        &self.mmap[..end]
+    }
+}
+
+/// Splits `bytes` into at most `count` pieces of about the same size, each
+/// ending at a line break.
+fn chunks(bytes: &[u8], count: usize) -> Vec<&[u8]> {
+    let size = bytes.len().div_ceil(count.max(1)).max(1);
+    let mut chunks = vec![];
+    let mut rest = bytes;
+    while !rest.is_empty() {
+        let end = match rest.get(size..) {
+            Some(tail) => match tail.iter().position(|&byte| byte == b'\n') {
+                Some(newline) => size + newline + 1,
+                None => rest.len(),
+            },
+            None => rest.len(),
+        };
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+    chunks
+}
+
+/// [`Calibration::sum`] of one chunk, which is checked to be UTF-8 by the
+/// thread that sums it.
+fn sum_chunk(chunk: &[u8], calibration: &Calibration) -> Result<(u64, Summary), SumError> {
+    let text = std::str::from_utf8(chunk).map_err(|err| SumError::InvalidUtf8 {
+        line: chunk[..err.valid_up_to()]
+            .iter()
+            .filter(|&&byte| byte == b'\n')
+            .count()
+            + 1,
+    })?;
+    calibration.sum(text.lines())
+}
+
+/// [`Calibration::sum`] over the lines of `bytes`, with the lines split
+/// between `threads` threads and their sums merged in order. Line numbers in
+/// errors count from the start of `bytes`; an overflow is reported at the line
+/// where it was noticed, which may be after the line that caused it.
+pub fn sum(
+    bytes: &[u8],
+    calibration: &Calibration,
+    threads: usize,
+) -> Result<(u64, Summary), SumError> {
+    let chunks = chunks(bytes, threads);
+    let results = std::thread::scope(|scope| {
+        let handles = chunks
+            .iter()
+            .map(|chunk| scope.spawn(|| sum_chunk(chunk, calibration)))
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("calibration thread panicked"))
+            .collect::<Vec<_>>()
+    });
+
+    let mut total_sum: u64 = 0;
+    let mut summary = Summary::default();
+    for result in results {
+        let lines_before = summary.lines();
+        match result {
+            Ok((chunk_sum, chunk_summary)) => {
+                summary.merge(&chunk_summary);
+                total_sum = accumulate(total_sum, chunk_sum, summary.lines())?;
+            }
+            Err(SumError::NoDigits(err)) => {
+                let line = lines_before + err.line;
+                return Err(SumError::NoDigits(err.on_line(line)));
+            }
+            Err(SumError::Overflow { line, total, value }) => {
+                return Err(SumError::Overflow {
+                    line: lines_before + line,
+                    total: total_sum.saturating_add(total),
+                    value,
+                })
+            }
+            Err(SumError::InvalidUtf8 { line }) => {
+                return Err(SumError::InvalidUtf8 {
+                    line: lines_before + line,
+                })
+            }
+        }
+    }
+    Ok((total_sum, summary))
+}
+
+#[cfg(test)]
+fn sequential(text: &str, calibration: &Calibration) -> Result<(u64, Summary), SumError> {
+    calibration.sum(text.lines())
+}
+
+#[test]
+fn test_chunks() {
+    let text = b"one\ntwo\n\nthree\nfour";
+    for count in 1..8 {
+        let chunks = chunks(text, count);
+        assert!(chunks.len() <= count);
+        assert_eq!(chunks.concat(), text);
+        for chunk in &chunks[..chunks.len() - 1] {
+            assert!(chunk.ends_with(b"\n"));
+        }
+    }
+    assert_eq!(
+        chunks(text, 2),
+        vec![&b"one\ntwo\n\nthree\n"[..], &b"four"[..]]
+    );
+    assert!(chunks(b"", 4).is_empty());
+}
+
+#[test]
+fn test_sum() {
+    use crate::calibration::{MatchRules, Missing};
+
+    let text = include_str!("../sample.part2.txt").repeat(50) + "ønsker\n7x\nfivesix";
+    let calibration = Calibration::new(&MatchRules::DigitsAndWords);
+    for threads in [1, 2, 3, 7, 64, 10_000] {
+        assert_eq!(
+            sum(text.as_bytes(), &calibration, threads),
+            sequential(&text, &calibration)
+        );
+    }
+    assert_eq!(sum(b"", &calibration, 4), sequential("", &calibration));
+
+    let strict = calibration.on_missing(Missing::Error);
+    let text = "1\n2\n3\n4\n5\nx\n7\n8";
+    assert_eq!(sum(text.as_bytes(), &strict, 3), sequential(text, &strict));
+    assert!(sum(text.as_bytes(), &strict, 3)
+        .unwrap_err()
+        .to_string()
+        .starts_with("line 6,"));
+}
+
+#[test]
+fn test_mapped() {
+    let path = std::env::temp_dir().join(format!("day1-mapped-{}.txt", std::process::id()));
+    let calibration = Calibration::new(&crate::calibration::MatchRules::Digits);
+
+    std::fs::write(&path, "a1b2\r\nc3\r\n\r\n").unwrap();
+    {
+        let mapped = Mapped::open(&path).unwrap();
+        assert_eq!(mapped.bytes(), b"a1b2\r\nc3");
+        assert_eq!(sum(mapped.bytes(), &calibration, 2).unwrap().0, 45);
+    }
+
+    std::fs::write(&path, "").unwrap();
+    assert_eq!(Mapped::open(&path).unwrap().bytes(), b"");
+
+    std::fs::write(&path, b"1\n2\n3\n4\n5\n6\xff\n7\n").unwrap();
+    {
+        let mapped = Mapped::open(&path).unwrap();
+        for threads in [1, 3] {
+            assert_eq!(
+                sum(mapped.bytes(), &calibration, threads),
+                Err(SumError::InvalidUtf8 { line: 6 })
+            );
+        }
+    }
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,73 @@
+//! Invariants of the calibration scanner over generated lines.
+
+use crate::calibration::{Calibration, MatchRules};
+use crate::vocabulary::Vocabulary;
+use proptest::prelude::*;
+
+/// Scans `line` the slow way: from the front for the first digit or word
+/// starting at a character, and from the back for the last one ending there.
+fn reference(line: &str, words: bool) -> Option<u32> {
+    let english = Vocabulary::english();
+    let vocabulary: Vec<(&str, u32)> = match words {
+        true => english.words().collect(),
+        false => vec![],
+    };
+
+    let first = line.char_indices().find_map(|(i, c)| match c {
+        '0'..='9' => c.to_digit(10),
+        _ => vocabulary
+            .iter()
+            .find(|(word, _)| line[i..].starts_with(word))
+            .map(|(_, value)| *value),
+    })?;
+
+    let ends = line.char_indices().map(|(i, c)| (i + c.len_utf8(), c));
+    let last = ends.rev().find_map(|(end, c)| match c {
+        '0'..='9' => c.to_digit(10),
+        _ => vocabulary
+            .iter()
+            .find(|(word, _)| line[..end].ends_with(word))
+            .map(|(_, value)| *value),
+    })?;
+
+    Some(first * 10 + last)
+}
+
+/// Lines made of pieces of number words, digits and other characters, so
+/// words often appear, overlap or almost appear.
+fn line() -> impl Strategy<Value = String> {
+    let pieces = prop::sample::select(vec![
+        "one", "two", "thr", "ee", "four", "fi", "ve", "six", "sev", "en", "eight", "nin", "e",
+        "t", "o", "n", "x", "0", "1", "5", "9", "ø", "-", " ",
+    ]);
+    prop::collection::vec(pieces, 0..12).prop_map(|pieces| pieces.concat())
+}
+
+proptest! {
+    #[test]
+    fn parts_agree_without_letters(line in "[0-9 ,.;:!?#*ø½²-]{0,40}") {
+        let part1 = Calibration::new(&MatchRules::Digits);
+        let part2 = Calibration::new(&MatchRules::DigitsAndWords);
+        prop_assert_eq!(part1.value(&line), part2.value(&line));
+    }
+
+    #[test]
+    fn part1_matches_reference(line in line()) {
+        let part1 = Calibration::new(&MatchRules::Digits);
+        prop_assert_eq!(part1.value(&line), reference(&line, false));
+    }
+
+    #[test]
+    fn part2_matches_reference(line in line()) {
+        let part2 = Calibration::new(&MatchRules::DigitsAndWords);
+        prop_assert_eq!(part2.value(&line), reference(&line, true));
+    }
+
+    #[test]
+    fn values_are_two_digits(line in any::<String>()) {
+        for rules in [MatchRules::Digits, MatchRules::DigitsAndWords, MatchRules::WordsOnly] {
+            let value = Calibration::new(&rules).value(&line);
+            prop_assert!(value.is_none_or(|value| value <= 99));
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use common::ParseError;
+use std::fmt;
+use std::path::Path;
+
+const ENGLISH: &[(&str, u32)] = &[
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+const GERMAN: &[(&str, u32)] = &[
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+const DANISH: &[(&str, u32)] = &[
+    ("en", 1),
+    ("to", 2),
+    ("tre", 3),
+    ("fire", 4),
+    ("fem", 5),
+    ("seks", 6),
+    ("syv", 7),
+    ("otte", 8),
+    ("ni", 9),
+];
+
+const FRENCH: &[(&str, u32)] = &[
+    ("un", 1),
+    ("deux", 2),
+    ("trois", 3),
+    ("quatre", 4),
+    ("cinq", 5),
+    ("six", 6),
+    ("sept", 7),
+    ("huit", 8),
+    ("neuf", 9),
+];
+
+/// The language codes accepted by [`Vocabulary::language`].
+pub const LANGUAGES: &[&str] = &["en", "de", "da", "fr"];
+
+/// The words that count as digits when reading a calibration line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+#[derive(Debug)]
+pub enum VocabularyError {
+    UnknownLanguage(String),
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    Parse {
+        path: String,
+        text: String,
+        error: ParseError,
+    },
+}
+
+impl fmt::Display for VocabularyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VocabularyError::UnknownLanguage(code) => write!(
+                f,
+                "unknown language '{}', expected one of {}",
+                code,
+                LANGUAGES.join(", ")
+            ),
+            VocabularyError::Io { path, source } => write!(f, "{}: {}", path, source),
+            VocabularyError::Parse { path, text, error } => {
+                write!(f, "{}", error.render(path, text))
+            }
+        }
+    }
+}
+
+impl std::error::Error for VocabularyError {}
+
+/// Two words where the first contains the second, so both can match around
+/// the same position of a line.
+#[derive(Debug, PartialEq)]
+pub struct Overlap<'a> {
+    pub word: &'a str,
+    pub contains: &'a str,
+}
+
+impl Vocabulary {
+    /// The spelled-out digits of the puzzle.
+    pub fn english() -> Vocabulary {
+        Vocabulary::from_table(ENGLISH)
+    }
+
+    /// One of the built-in tables, by its code in [`LANGUAGES`].
+    pub fn language(code: &str) -> Result<Vocabulary, VocabularyError> {
+        let table = match code {
+            "en" => ENGLISH,
+            "de" => GERMAN,
+            "da" => DANISH,
+            "fr" => FRENCH,
+            _ => return Err(VocabularyError::UnknownLanguage(code.to_string())),
+        };
+        Ok(Vocabulary::from_table(table))
+    }
+
+    fn from_table(table: &[(&str, u32)]) -> Vocabulary {
+        Vocabulary {
+            words: table
+                .iter()
+                .map(|(word, value)| (word.to_string(), *value))
+                .collect(),
+        }
+    }
+
+    /// Reads a vocabulary file, see [`Vocabulary::parse`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Vocabulary, VocabularyError> {
+        let path = path.as_ref().display().to_string();
+        let text = std::fs::read_to_string(&path).map_err(|source| VocabularyError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        Vocabulary::parse(&text).map_err(|error| VocabularyError::Parse { path, text, error })
+    }
+
+    /// Parses one `word = digit` pair per line. Blank lines and lines
+    /// starting with `#` are skipped. Values must be 0-9, and a word may only
+    /// be listed twice with the same value.
+    pub fn parse(text: &str) -> Result<Vocabulary, ParseError> {
+        let mut words: Vec<(String, u32)> = vec![];
+        for (i, line) in text.lines().enumerate() {
+            let entry = line.trim();
+            if entry.is_empty() || entry.starts_with('#') {
+                continue;
+            }
+            let (word, value) = read_entry(line).map_err(|err| err.on_line(i + 1))?;
+            match words.iter().find(|(known, _)| known == word) {
+                Some((_, known)) if *known == value => continue,
+                Some((_, known)) => {
+                    let message = format!("ambiguous word, already maps to {}", known);
+                    return Err(ParseError::at(line, word, message).on_line(i + 1));
+                }
+                None => words.push((word.to_string(), value)),
+            }
+        }
+        Ok(Vocabulary { words })
+    }
+
+    /// Pairs of words where one contains the other. Where both match, the
+    /// longest word wins.
+    pub fn overlaps(&self) -> Vec<Overlap<'_>> {
+        let mut overlaps = vec![];
+        for (word, _) in &self.words {
+            for (other, _) in &self.words {
+                if word != other && word.contains(other.as_str()) {
+                    overlaps.push(Overlap {
+                        word,
+                        contains: other,
+                    });
+                }
+            }
+        }
+        overlaps
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+    }
+}
+
+fn read_entry(line: &str) -> Result<(&str, u32), ParseError> {
+    let (word, value) = line
+        .split_once('=')
+        .ok_or_else(|| ParseError::at(line, line, "expected 'word = digit'"))?;
+    let (word, value) = (word.trim(), value.trim());
+    if word.is_empty() {
+        return Err(ParseError::at(line, line, "missing word"));
+    }
+    match value.parse::<u32>() {
+        Ok(digit) if digit <= 9 => Ok((word, digit)),
+        _ => Err(ParseError::at(line, value, "value must be a digit 0-9")),
+    }
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(
+        Vocabulary::parse("# Danish\nen = 1\n\nto=2\nen = 1\n").unwrap(),
+        Vocabulary {
+            words: vec![("en".to_string(), 1), ("to".to_string(), 2)],
+        }
+    );
+    assert_eq!(
+        Vocabulary::parse("one = 1\nten = 10"),
+        Err(ParseError {
+            line: 2,
+            column: 7,
+            text: "10".to_string(),
+            message: "value must be a digit 0-9".to_string(),
+        })
+    );
+    assert_eq!(
+        Vocabulary::parse("one = 1\none = 7"),
+        Err(ParseError {
+            line: 2,
+            column: 1,
+            text: "one".to_string(),
+            message: "ambiguous word, already maps to 1".to_string(),
+        })
+    );
+    assert!(Vocabulary::parse("one 1").is_err());
+    assert!(Vocabulary::parse(" = 1").is_err());
+}
+
+#[test]
+fn test_overlaps() {
+    for code in LANGUAGES {
+        assert_eq!(Vocabulary::language(code).unwrap().overlaps(), vec![]);
+    }
+    let vocabulary = Vocabulary::parse("ein = 1\neins = 1").unwrap();
+    assert_eq!(
+        vocabulary.overlaps(),
+        vec![Overlap {
+            word: "eins",
+            contains: "ein",
+        }]
+    );
+    assert!(Vocabulary::language("xx").is_err());
+}
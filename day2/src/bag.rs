@@ -0,0 +1,135 @@
+use crate::GemCount;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::Path;
+
+#[derive(Debug, PartialEq)]
+pub enum BagError {
+    /// An entry of a `--bag` list that isn't `<colour>=<count>`.
+    Entry(String),
+    Io {
+        path: String,
+        message: String,
+    },
+    Toml {
+        path: String,
+        message: String,
+    },
+    /// Colours the input names that the bag has no limit for.
+    Missing(Vec<String>),
+}
+
+impl fmt::Display for BagError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BagError::Entry(entry) => {
+                write!(
+                    f,
+                    "invalid bag entry '{}', expected <colour>=<count>",
+                    entry
+                )
+            }
+            BagError::Io { path, message } => write!(f, "could not read {}: {}", path, message),
+            BagError::Toml { path, message } => write!(f, "invalid {}: {}", path, message),
+            BagError::Missing(colours) => write!(
+                f,
+                "the bag has no limit for {}, which the input names",
+                colours.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BagError {}
+
+/// The bag of the puzzle: 12 red, 13 green and 14 blue cubes.
+pub fn puzzle() -> GemCount {
+    GemCount::new([("red", 12), ("green", 13), ("blue", 14)])
+}
+
+/// Parses a list like `red=12,green=13,blue=14`.
+pub fn parse(list: &str) -> Result<GemCount, BagError> {
+    let mut counts = BTreeMap::new();
+    for entry in list.split(',') {
+        let (colour, count) = entry
+            .split_once('=')
+            .ok_or_else(|| BagError::Entry(entry.to_string()))?;
+        let (colour, count) = (colour.trim(), count.trim());
+        let count = count
+            .parse::<u32>()
+            .map_err(|_| BagError::Entry(entry.to_string()))?;
+        if colour.is_empty() {
+            return Err(BagError::Entry(entry.to_string()));
+        }
+        counts.insert(colour.to_string(), count);
+    }
+    Ok(GemCount { counts })
+}
+
+/// Reads a TOML file of `<colour> = <count>` pairs.
+pub fn load(path: impl AsRef<Path>) -> Result<GemCount, BagError> {
+    let path = path.as_ref().display().to_string();
+    let text = std::fs::read_to_string(&path).map_err(|err| BagError::Io {
+        path: path.clone(),
+        message: err.to_string(),
+    })?;
+    let counts = toml::from_str::<BTreeMap<String, u32>>(&text).map_err(|err| BagError::Toml {
+        path,
+        message: err.message().to_string(),
+    })?;
+    Ok(GemCount { counts })
+}
+
+/// Checks that `bag` has a limit for every colour in `colours`, and returns
+/// the colours it has a limit for that `colours` doesn't name.
+pub fn check<'b>(bag: &'b GemCount, colours: &BTreeSet<&str>) -> Result<Vec<&'b str>, BagError> {
+    let missing = colours
+        .iter()
+        .filter(|colour| !bag.counts.contains_key(**colour))
+        .map(|colour| colour.to_string())
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        return Err(BagError::Missing(missing));
+    }
+    Ok(bag
+        .counts
+        .keys()
+        .map(String::as_str)
+        .filter(|colour| !colours.contains(colour))
+        .collect())
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(parse("red=12, green=13,blue = 14"), Ok(puzzle()));
+    assert_eq!(
+        parse("red=12,green"),
+        Err(BagError::Entry("green".to_string()))
+    );
+    assert_eq!(parse("red=-1"), Err(BagError::Entry("red=-1".to_string())));
+    assert!(parse("=3").is_err());
+}
+
+#[test]
+fn test_load() {
+    let path = std::env::temp_dir().join(format!("day2-bag-{}.toml", std::process::id()));
+    std::fs::write(&path, "red = 12\ngreen = 13\nblue = 14\n").unwrap();
+    assert_eq!(load(&path), Ok(puzzle()));
+    std::fs::write(&path, "red = \"many\"\n").unwrap();
+    assert!(matches!(load(&path), Err(BagError::Toml { .. })));
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(load(&path), Err(BagError::Io { .. })));
+}
+
+#[test]
+fn test_check() {
+    let bag = parse("red=1,green=2,pink=3").unwrap();
+    assert_eq!(check(&bag, &["red", "green"].into()), Ok(vec!["pink"]));
+    assert_eq!(
+        check(&bag, &["red", "blue", "yellow"].into()),
+        Err(BagError::Missing(vec![
+            "blue".to_string(),
+            "yellow".to_string()
+        ]))
+    );
+}
@@ -0,0 +1,3 @@
+fn main() {
+    day2::cli::main(day2::cli::Part::Two);
+}
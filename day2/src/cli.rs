@@ -0,0 +1,166 @@
+use crate::{bag, Colours, GemCount};
+use common::input::{self, Source};
+
+pub const USAGE: &str = "\
+usage: day2-part1|day2-part2 [options] <input>
+
+<input> is a path, - for stdin or a day number.
+
+options:
+  --colours <list>      only allow these comma-separated colours, e.g. red,green,blue
+  --bag <limits>        part 1: the cubes in the bag (default red=12,green=13,blue=14)
+  --bag-file <file>     part 1: the cubes in the bag from a TOML file of 'colour = count' lines
+  --report              part 1: list every round of an illegal game that exceeds the bag";
+
+/// Which part a day 2 binary answers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Part {
+    /// The sum of the ids of the games the bag allows.
+    One,
+    /// The sum of the powers of the fewest cubes of each game.
+    Two,
+}
+
+/// Command-line options of the day 2 binaries.
+#[derive(Debug)]
+pub struct Options {
+    pub input: Source,
+    pub colours: Colours,
+    pub bag: GemCount,
+    /// Print the rounds that make games illegal before the answers.
+    pub report: bool,
+}
+
+pub fn parse(args: &[String]) -> Result<Options, String> {
+    let mut input = None;
+    let mut colours = Colours::Any;
+    let mut bag = None;
+    let mut report = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--colours" => {
+                let list = value()?;
+                let names = list
+                    .split(',')
+                    .map(|colour| colour.trim().to_string())
+                    .collect::<Vec<_>>();
+                if names.iter().any(String::is_empty) {
+                    return Err(format!("invalid colour list '{}'", list));
+                }
+                colours = Colours::Only(names);
+            }
+            "--bag" | "--bag-file" if bag.is_some() => {
+                return Err("only one of --bag and --bag-file may be given".to_string())
+            }
+            "--bag" => bag = Some(bag::parse(value()?).map_err(|err| err.to_string())?),
+            "--bag-file" => bag = Some(bag::load(value()?).map_err(|err| err.to_string())?),
+            "--report" => report = true,
+            flag if flag.starts_with("--") => return Err(format!("unknown option {}", flag)),
+            _ if input.is_some() => return Err(format!("unexpected argument {}", arg)),
+            _ => input = Some(Source::from_arg(arg)),
+        }
+    }
+
+    Ok(Options {
+        input: input.ok_or("missing input argument")?,
+        colours,
+        bag: bag.unwrap_or_else(bag::puzzle),
+        report,
+    })
+}
+
+/// [`parse`] on the process arguments: prints the usage and exits on error.
+pub fn from_env() -> Options {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    parse(&args).unwrap_or_else(|message| {
+        eprintln!("error: {}\n\n{}", message, USAGE);
+        std::process::exit(2);
+    })
+}
+
+/// Runs a day 2 binary: reads the games and prints the answer to `part`.
+pub fn main(part: Part) {
+    let options = from_env();
+    let file_content = input::load(&options.input).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    });
+
+    let games = crate::read_games_with(&file_content, &options.colours)
+        .unwrap_or_else(|err| err.exit(&options.input.name(), &file_content));
+    match part {
+        Part::One => main_part_one(&games, &options),
+        Part::Two => {
+            let powers = crate::part_two(&games).unwrap_or_else(|err| exit(err));
+            println!("Sum of powers: {}", powers);
+        }
+    }
+}
+
+fn main_part_one(games: &[crate::Game], options: &Options) {
+    let unused = bag::check(&options.bag, &crate::colours(games)).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    });
+    for colour in unused {
+        eprintln!(
+            "warning: the input never names {}, which the bag has",
+            colour
+        );
+    }
+
+    if options.report {
+        let report = crate::report::report(games, &options.bag);
+        print!("{}", crate::report::table(&report));
+        println!("{} of {} games are illegal\n", report.len(), games.len());
+    }
+    println!("Total games: {}", games.len());
+    println!(
+        "Sum of legal game IDs: {}",
+        crate::part_one_with(games, &options.bag).unwrap_or_else(|err| exit(err))
+    );
+}
+
+fn exit(err: crate::Overflow) -> ! {
+    eprintln!("error: {}", err);
+    std::process::exit(1);
+}
+
+#[test]
+fn test_parse() {
+    let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+    let options = parse(&args(&["2"])).unwrap();
+    assert_eq!(options.input, Source::Day(2));
+    assert_eq!(options.colours, Colours::Any);
+    assert_eq!(options.bag, bag::puzzle());
+    assert!(!options.report);
+
+    let options = parse(&args(&["--bag", "red=1,pink=2", "2"])).unwrap();
+    assert_eq!(options.bag, GemCount::new([("red", 1), ("pink", 2)]));
+
+    let options = parse(&args(&["2", "--report"])).unwrap();
+    assert!(options.report);
+
+    let options = parse(&args(&["--colours", "red, green,blue", "-"])).unwrap();
+    assert_eq!(options.input, Source::Stdin);
+    assert_eq!(
+        options.colours,
+        Colours::Only(vec![
+            "red".to_string(),
+            "green".to_string(),
+            "blue".to_string()
+        ])
+    );
+
+    assert!(parse(&args(&[])).is_err());
+    assert!(parse(&args(&["--colours", "red,,blue", "2"])).is_err());
+    assert!(parse(&args(&["--colours"])).is_err());
+    assert!(parse(&args(&["--bag", "red", "2"])).is_err());
+    assert!(parse(&args(&["--bag-file", "missing.toml", "2"])).is_err());
+    assert!(parse(&args(&["--bag", "red=1", "--bag", "red=2", "2"])).is_err());
+    assert!(parse(&args(&["--verbose", "2"])).is_err());
+    assert!(parse(&args(&["1", "2"])).is_err());
+}
@@ -0,0 +1,490 @@
+use common::{ParseError, Solution, SolveError};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+pub mod bag;
+pub mod cli;
+#[cfg(test)]
+mod properties;
+pub mod report;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const SOLVES_PART2: bool = true;
+
+    type Input = Vec<Game>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_games(input)
+    }
+
+    fn part1(games: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        part_one(games).map_err(SolveError::from)
+    }
+
+    fn part2(games: &Self::Input) -> Option<Result<Self::Answer2, SolveError>> {
+        Some(part_two(games).map_err(SolveError::from))
+    }
+}
+
+common::samples! {
+    Day2,
+    sample: "sample.txt" => { part1: 8, part2: 2286 },
+}
+
+pub fn part_one(games: &[Game]) -> Result<u64, Overflow> {
+    part_one_with(games, &bag::puzzle())
+}
+
+/// [`part_one`] with the limits of another bag.
+pub fn part_one_with(games: &[Game], bag: &GemCount) -> Result<u64, Overflow> {
+    let legal_games = games
+        .iter()
+        .filter(|game| is_game_legal(game, bag))
+        .collect::<Vec<&Game>>();
+    let mut legal_games_id_acum: u64 = 0;
+    for game in legal_games {
+        legal_games_id_acum = accumulate(legal_games_id_acum, game.game_id as u64, game)?;
+    }
+    Ok(legal_games_id_acum)
+}
+
+pub fn part_two(games: &[Game]) -> Result<u64, Overflow> {
+    let colours = colours(games);
+    let mut power_acum: u64 = 0;
+    for game in games {
+        let power = fewest_gems_per_game(game)
+            .power(&colours)
+            .ok_or(Overflow::Power { game: game.game_id })?;
+        power_acum = accumulate(power_acum, power, game)?;
+    }
+    Ok(power_acum)
+}
+
+/// An answer that doesn't fit in a u64.
+#[derive(Debug, PartialEq)]
+pub enum Overflow {
+    /// Adding the id or power of `game` to the total so far overflowed.
+    Sum { game: u32, total: u64, value: u64 },
+    /// The product of the fewest cubes of `game` overflowed.
+    Power { game: u32 },
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Overflow::Sum { game, total, value } => write!(
+                f,
+                "sum overflows at game {}: {} + {} does not fit in a u64",
+                game, total, value
+            ),
+            Overflow::Power { game } => {
+                write!(f, "the power of game {} does not fit in a u64", game)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+impl From<Overflow> for SolveError {
+    fn from(err: Overflow) -> SolveError {
+        SolveError::new(err.to_string())
+    }
+}
+
+/// Adds `value` to `total`, failing at `game` when it overflows.
+fn accumulate(total: u64, value: u64, game: &Game) -> Result<u64, Overflow> {
+    total.checked_add(value).ok_or(Overflow::Sum {
+        game: game.game_id,
+        total,
+        value,
+    })
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Game {
+    pub game_id: u32,
+    pub rounds: Vec<Draw>,
+}
+
+/// The cubes of one round, per colour in the order they were named.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Draw {
+    pub cubes: Vec<(String, u32)>,
+}
+
+impl Draw {
+    pub fn new<'a>(cubes: impl IntoIterator<Item = (&'a str, u32)>) -> Draw {
+        Draw {
+            cubes: cubes
+                .into_iter()
+                .map(|(colour, count)| (colour.to_string(), count))
+                .collect(),
+        }
+    }
+
+    /// How many cubes of `colour` were drawn, 0 if it wasn't named.
+    pub fn count(&self, colour: &str) -> u32 {
+        self.cubes
+            .iter()
+            .filter(|(name, _)| name == colour)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.cubes.iter().map(|(colour, _)| colour.as_str())
+    }
+}
+
+/// The game as a puzzle line, e.g. `Game 1: 3 blue, 4 red; 2 green`, which
+/// [`read_game`] reads back.
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Game {}: ", self.game_id)?;
+        for (i, round) in self.rounds.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", round)?;
+        }
+        Ok(())
+    }
+}
+
+/// The draw as in a puzzle line, e.g. `3 blue, 4 red`.
+impl fmt::Display for Draw {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, (colour, count)) in self.cubes.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{} {}", count, colour)?;
+        }
+        Ok(())
+    }
+}
+
+/// A number of cubes per colour; colours that aren't listed have none.
+#[derive(Debug, Default, PartialEq)]
+pub struct GemCount {
+    counts: BTreeMap<String, u32>,
+}
+
+impl GemCount {
+    pub fn new<'a>(counts: impl IntoIterator<Item = (&'a str, u32)>) -> GemCount {
+        GemCount {
+            counts: counts
+                .into_iter()
+                .map(|(colour, count)| (colour.to_string(), count))
+                .collect(),
+        }
+    }
+
+    pub fn get(&self, colour: &str) -> u32 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+
+    /// The product of the counts of `colours`, `None` if it overflows.
+    fn power(&self, colours: &BTreeSet<&str>) -> Option<u64> {
+        colours.iter().try_fold(1u64, |power, colour| {
+            power.checked_mul(self.get(colour) as u64)
+        })
+    }
+}
+
+/// Every colour named in `games`.
+pub fn colours(games: &[Game]) -> BTreeSet<&str> {
+    games
+        .iter()
+        .flat_map(|game| &game.rounds)
+        .flat_map(Draw::colours)
+        .collect()
+}
+
+fn fewest_gems_per_game(game: &Game) -> GemCount {
+    let mut fewest_gems = GemCount::default();
+
+    for round in &game.rounds {
+        for colour in round.colours() {
+            let fewest = fewest_gems.counts.entry(colour.to_string()).or_insert(0);
+            *fewest = (*fewest).max(round.count(colour));
+        }
+    }
+    fewest_gems
+}
+
+#[test]
+fn test_fewest_gems_per_game() {
+    let game = read_game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 yellow").unwrap();
+    assert_eq!(
+        fewest_gems_per_game(&game),
+        GemCount::new([("blue", 6), ("green", 2), ("red", 4), ("yellow", 2)])
+    );
+    let games = vec![game, read_game("Game 2: 1 red, 2 green, 3 blue").unwrap()];
+    assert_eq!(part_two(&games), Ok(6 * 2 * 4 * 2));
+}
+
+#[test]
+fn test_part_two() {
+    let games = read_games(include_str!("../sample.txt")).unwrap();
+    assert_eq!(part_two(&games), Ok(2286));
+    let large = read_game("Game 1: 100000 red, 100000 green, 100000 blue").unwrap();
+    assert_eq!(part_two(&[large]), Ok(1_000_000_000_000_000));
+
+    let huge = read_game("Game 7: 100000 red, 100000 green, 100000 blue, 100000 pink, 100000 cyan")
+        .unwrap();
+    assert_eq!(part_two(&[huge]), Err(Overflow::Power { game: 7 }));
+    let games = read_games(
+        "Game 1: 4294967295 red, 4294967295 green\nGame 2: 4294967295 red, 4294967295 green",
+    )
+    .unwrap();
+    assert_eq!(
+        part_two(&games),
+        Err(Overflow::Sum {
+            game: 2,
+            total: 18446744065119617025,
+            value: 18446744065119617025,
+        })
+    );
+    assert_eq!(
+        Overflow::Power { game: 7 }.to_string(),
+        "the power of game 7 does not fit in a u64"
+    );
+}
+
+#[test]
+fn test_part_one_with() {
+    let games = read_games(include_str!("../sample.txt")).unwrap();
+    assert_eq!(part_one_with(&games, &bag::puzzle()), Ok(8));
+    let large = bag::parse("red=20,green=13,blue=15").unwrap();
+    assert_eq!(part_one_with(&games, &large), Ok(15));
+    let small = bag::parse("red=5,green=5,blue=5").unwrap();
+    assert_eq!(part_one_with(&games, &small), Ok(2));
+    let games = read_games("Game 4294967295: 1 red\nGame 2: 1 red").unwrap();
+    assert_eq!(part_one(&games), Ok(4294967297));
+}
+
+#[test]
+fn test_is_draw_legal() {
+    let max_gems = bag::puzzle();
+    assert!(!is_draw_legal(
+        &Draw::new([("green", 8), ("red", 20), ("blue", 6)]),
+        &max_gems
+    ));
+    assert!(is_draw_legal(
+        &Draw::new([("red", 1), ("blue", 14), ("green", 1)]),
+        &max_gems
+    ));
+    assert!(!is_draw_legal(&Draw::new([("purple", 1)]), &max_gems));
+    assert!(!is_draw_legal(
+        &Draw::new([("red", 7), ("red", 6)]),
+        &max_gems
+    ));
+}
+
+fn is_draw_legal(draw: &Draw, gem_count: &GemCount) -> bool {
+    draw.colours()
+        .all(|colour| draw.count(colour) <= gem_count.get(colour))
+}
+
+fn is_game_legal(game: &Game, gem_count: &GemCount) -> bool {
+    for round in &game.rounds {
+        let is_legal = is_draw_legal(round, gem_count);
+        if !is_legal {
+            return false;
+        }
+    }
+    true
+}
+
+#[test]
+fn test_display() {
+    let line = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+    assert_eq!(read_game(line).unwrap().to_string(), line);
+    assert_eq!(
+        Draw::new([("purple", 2), ("red", 1), ("purple", 3)]).to_string(),
+        "2 purple, 1 red, 3 purple"
+    );
+}
+
+#[test]
+fn test_read_game() {
+    assert_eq!(
+        read_game("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"),
+        Ok(Game {
+            game_id: 3,
+            rounds: vec![
+                Draw::new([("green", 8), ("blue", 6), ("red", 20)]),
+                Draw::new([("blue", 5), ("red", 4), ("green", 13)]),
+                Draw::new([("green", 5), ("red", 1)]),
+            ],
+        })
+    );
+    assert_eq!(
+        read_game("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"),
+        Ok(Game {
+            game_id: 5,
+            rounds: vec![
+                Draw::new([("red", 6), ("blue", 1), ("green", 3)]),
+                Draw::new([("blue", 2), ("red", 1), ("green", 2)]),
+            ]
+        })
+    );
+    assert_eq!(
+        read_game("Game 11: 1 red"),
+        Ok(Game {
+            game_id: 11,
+            rounds: vec![Draw::new([("red", 1)])]
+        })
+    );
+    assert_eq!(
+        read_game("Game 95: 1 red, 7 blue, 2 green; 3 red, 14 blue, 2 green; 1 red; 1 red, 14 blue, 1 green; 4 blue, 10 red, 2 green; 9 blue, 7 red"),
+        Ok(Game {
+            game_id: 95,
+            rounds: vec![
+                Draw::new([("red", 1), ("blue", 7), ("green", 2)]),
+                Draw::new([("red", 3), ("blue", 14), ("green", 2)]),
+                Draw::new([("red", 1)]),
+                Draw::new([("red", 1), ("blue", 14), ("green", 1)]),
+                Draw::new([("blue", 4), ("red", 10), ("green", 2)]),
+                Draw::new([("blue", 9), ("red", 7)]),
+            ]
+        })
+    );
+    assert_eq!(
+        read_game("Game 4: 3 blue, x red"),
+        Err(ParseError {
+            line: 1,
+            column: 17,
+            text: "x".to_string(),
+            message: "invalid cube count".to_string(),
+        })
+    );
+    assert!(read_game("Game 4 3 blue").is_err());
+    assert!(read_game("Game four: 3 blue").is_err());
+    assert!(read_game("Game 4: 3blue").is_err());
+    for (line, column, text) in [
+        ("Game 4: 3 blue;", 11, "blue;"),
+        ("Game 4: 3 ", 11, ""),
+        ("Game 4: 2 red,4 green", 11, "red,4 green"),
+        ("Game 4: 1 red; 2  blue", 18, " blue"),
+        ("Game 4: 1 Red", 11, "Red"),
+    ] {
+        assert_eq!(
+            read_game(line),
+            Err(ParseError {
+                line: 1,
+                column,
+                text: text.to_string(),
+                message: "invalid colour, expected a lowercase word".to_string(),
+            })
+        );
+    }
+
+    let rgb = Colours::Only(vec![
+        "red".to_string(),
+        "green".to_string(),
+        "blue".to_string(),
+    ]);
+    assert_eq!(
+        read_game_with("Game 6: 2 purple, 1 red; 3 yellow", &Colours::Any),
+        Ok(Game {
+            game_id: 6,
+            rounds: vec![
+                Draw::new([("purple", 2), ("red", 1)]),
+                Draw::new([("yellow", 3)]),
+            ],
+        })
+    );
+    assert_eq!(
+        read_game_with("Game 6: 1 red, 2 purple", &rgb),
+        Err(ParseError {
+            line: 1,
+            column: 18,
+            text: "purple".to_string(),
+            message: "unknown colour, expected one of red, green, blue".to_string(),
+        })
+    );
+}
+
+/// Which colours a game may name.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Colours {
+    Any,
+    /// Only these; any other colour is an error.
+    Only(Vec<String>),
+}
+
+pub fn read_games(file_content: &str) -> Result<Vec<Game>, ParseError> {
+    read_games_with(file_content, &Colours::Any)
+}
+
+/// [`read_games`] limited to `colours`.
+pub fn read_games_with(file_content: &str, colours: &Colours) -> Result<Vec<Game>, ParseError> {
+    file_content
+        .lines()
+        .enumerate()
+        .map(|(i, line)| read_game_with(line, colours).map_err(|err| err.on_line(i + 1)))
+        .collect::<Result<Vec<Game>, ParseError>>()
+}
+
+pub fn read_game(line: &str) -> Result<Game, ParseError> {
+    read_game_with(line, &Colours::Any)
+}
+
+pub fn read_game_with(line: &str, colours: &Colours) -> Result<Game, ParseError> {
+    let (game, draws) = line
+        .split_once(": ")
+        .ok_or_else(|| ParseError::at(line, line, "expected 'Game <id>: <draws>'"))?;
+    let game_id = match game.split_once(' ') {
+        Some(("Game", id)) => id
+            .parse::<u32>()
+            .map_err(|_| ParseError::at(line, id, "invalid game id"))?,
+        _ => return Err(ParseError::at(line, game, "expected 'Game <id>'")),
+    };
+
+    let mut rounds: Vec<Draw> = vec![];
+    for round in draws.split("; ") {
+        let draw = round_draw(line, round, colours)?;
+        rounds.push(draw);
+    }
+
+    Ok(Game { game_id, rounds })
+}
+
+fn round_draw(line: &str, round: &str, colours: &Colours) -> Result<Draw, ParseError> {
+    let mut draw = Draw { cubes: vec![] };
+
+    for color in round.split(", ") {
+        let (count, color_name) = color
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(line, color, "expected '<count> <colour>'"))?;
+        let count = count
+            .parse::<u32>()
+            .map_err(|_| ParseError::at(line, count, "invalid cube count"))?;
+
+        if color_name.is_empty() || !color_name.bytes().all(|byte| byte.is_ascii_lowercase()) {
+            return Err(ParseError::at(
+                line,
+                color_name,
+                "invalid colour, expected a lowercase word",
+            ));
+        }
+        if let Colours::Only(known) = colours {
+            if !known.iter().any(|known| known == color_name) {
+                let message = format!("unknown colour, expected one of {}", known.join(", "));
+                return Err(ParseError::at(line, color_name, message));
+            }
+        }
+        draw.cubes.push((color_name.to_string(), count));
+    }
+
+    Ok(draw)
+}
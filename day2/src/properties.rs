@@ -0,0 +1,29 @@
+//! Round trips of games through their puzzle line and JSON.
+
+use crate::{read_game, Draw, Game};
+use proptest::prelude::*;
+
+/// Draws of one to four cubes; colours may repeat within a draw.
+fn draw() -> impl Strategy<Value = Draw> {
+    let cube = ("[a-z]{1,8}", 0..1_000u32);
+    prop::collection::vec(cube, 1..5).prop_map(|cubes| Draw { cubes })
+}
+
+/// Games of one to six draws, as every puzzle line has at least one.
+fn game() -> impl Strategy<Value = Game> {
+    (any::<u32>(), prop::collection::vec(draw(), 1..7))
+        .prop_map(|(game_id, rounds)| Game { game_id, rounds })
+}
+
+proptest! {
+    #[test]
+    fn line_round_trips(game in game()) {
+        prop_assert_eq!(read_game(&game.to_string()), Ok(game));
+    }
+
+    #[test]
+    fn json_round_trips(game in game()) {
+        let json = serde_json::to_string(&game).unwrap();
+        prop_assert_eq!(serde_json::from_str::<Game>(&json).unwrap(), game);
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{Draw, Game, GemCount};
+
+/// One colour of one round that drew more cubes than the bag holds.
+#[derive(Debug, PartialEq)]
+pub struct Violation {
+    /// The index of the round in its game, from 0.
+    pub round: usize,
+    pub colour: String,
+    pub count: u32,
+    pub limit: u32,
+}
+
+/// Every colour of every round of `game` that exceeds `bag`; empty when
+/// the game is legal.
+pub fn violations(game: &Game, bag: &GemCount) -> Vec<Violation> {
+    let mut violations = vec![];
+    for (round, draw) in game.rounds.iter().enumerate() {
+        for colour in unique_colours(draw) {
+            let (count, limit) = (draw.count(colour), bag.get(colour));
+            if count > limit {
+                violations.push(Violation {
+                    round,
+                    colour: colour.to_string(),
+                    count,
+                    limit,
+                });
+            }
+        }
+    }
+    violations
+}
+
+/// The colours of `draw` in the order they were first named.
+fn unique_colours(draw: &Draw) -> Vec<&str> {
+    let mut colours: Vec<&str> = vec![];
+    for colour in draw.colours() {
+        if !colours.contains(&colour) {
+            colours.push(colour);
+        }
+    }
+    colours
+}
+
+/// The illegal games of `games` with their violations.
+pub fn report<'g>(games: &'g [Game], bag: &GemCount) -> Vec<(&'g Game, Vec<Violation>)> {
+    games
+        .iter()
+        .map(|game| (game, violations(game, bag)))
+        .filter(|(_, violations)| !violations.is_empty())
+        .collect()
+}
+
+/// A table with a row per violation, rounds numbered from 1.
+pub fn table(report: &[(&Game, Vec<Violation>)]) -> String {
+    let mut rows = vec![[
+        "game".to_string(),
+        "round".to_string(),
+        "colour".to_string(),
+        "count".to_string(),
+        "limit".to_string(),
+    ]];
+    for (game, violations) in report {
+        for violation in violations {
+            rows.push([
+                game.game_id.to_string(),
+                (violation.round + 1).to_string(),
+                violation.colour.clone(),
+                violation.count.to_string(),
+                violation.limit.to_string(),
+            ]);
+        }
+    }
+
+    let mut widths = [0; 5];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let mut table = String::new();
+    for row in &rows {
+        let line = format!(
+            "{:>w0$}  {:>w1$}  {:<w2$}  {:>w3$}  {:>w4$}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            row[4],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+            w4 = widths[4],
+        );
+        table += line.trim_end();
+        table.push('\n');
+    }
+    table
+}
+
+#[test]
+fn test_violations() {
+    let game = crate::read_game(
+        "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 15 blue, 9 red, 6 blue",
+    )
+    .unwrap();
+    assert_eq!(
+        violations(&game, &crate::bag::puzzle()),
+        vec![
+            Violation {
+                round: 0,
+                colour: "red".to_string(),
+                count: 20,
+                limit: 12,
+            },
+            Violation {
+                round: 2,
+                colour: "blue".to_string(),
+                count: 21,
+                limit: 14,
+            },
+        ]
+    );
+    let bag = crate::bag::parse("red=20,green=13,blue=14").unwrap();
+    assert_eq!(violations(&game, &bag).len(), 1);
+}
+
+#[test]
+fn test_report() {
+    let games = crate::read_games(include_str!("../sample.txt")).unwrap();
+    let bag = crate::bag::puzzle();
+    let report = report(&games, &bag);
+    for game in &games {
+        let reported = report
+            .iter()
+            .any(|(illegal, _)| illegal.game_id == game.game_id);
+        assert_eq!(reported, !crate::is_game_legal(game, &bag));
+    }
+    assert_eq!(
+        table(&report),
+        "\
+game  round  colour  count  limit
+   3      1  red        20     12
+   4      3  blue       15     14
+   4      3  red        14     12
+"
+    );
+}
@@ -0,0 +1,204 @@
+use common::{ParseError, Solution, SolveError};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Board;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_board(input)
+    }
+
+    fn part1(board: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(find_numbers(board).iter().sum::<u128>())
+    }
+}
+
+common::samples! {
+    Day3,
+    sample: "sample.txt" => { part1: 4361 },
+}
+
+pub type Board = Vec<Vec<char>>;
+
+#[test]
+fn test_find_numbers() {
+    const BOARD: &str = include_str!("../sample.txt");
+    assert_eq!(
+        find_numbers(
+            &read_board(
+                r#"467..114..
+...*......"#
+            )
+            .unwrap()
+        ),
+        vec![467]
+    );
+    assert_eq!(
+        find_numbers(&read_board(BOARD).unwrap()),
+        vec![467, 35, 633, 617, 592, 755, 664, 598]
+    );
+    assert_eq!(find_numbers(&read_board("467..114..").unwrap()), vec![]);
+    assert_eq!(
+        find_numbers(
+            &read_board(
+                r#"........
+.24..4..
+......*."#
+            )
+            .unwrap()
+        ),
+        vec![4]
+    );
+
+    assert_eq!(
+        find_numbers(
+            &read_board(
+                r#"........
+.24$-4..
+......*."#
+            )
+            .unwrap()
+        ),
+        vec![24, 4]
+    );
+
+    assert_eq!(
+        find_numbers(
+            &read_board(
+                r#"11....11
+..$..$..
+11....11"#
+            )
+            .unwrap()
+        ),
+        vec![11, 11, 11, 11]
+    );
+
+    assert_eq!(find_numbers(&read_board(r#"11.$."#).unwrap()), vec![]);
+}
+
+pub fn find_numbers(board: &Board) -> Vec<u128> {
+    let mut numbers: Vec<u128> = vec![];
+    for (_x, row) in board.iter().enumerate() {
+        let mut number: u128 = 0;
+        let mut number_started = false;
+        let mut number_has_adjacent_symbol = false;
+        for (_y, c) in row.iter().enumerate() {
+            if let Some(digit) = c.to_digit(10) {
+                number = number * 10 + digit as u128;
+                number_started = true;
+                if has_adjacent_symbol(board, (_x as u128, _y as u128)) {
+                    number_has_adjacent_symbol = true;
+                }
+            } else {
+                if number_started && number_has_adjacent_symbol {
+                    numbers.push(number);
+                }
+                number_started = false;
+                number = 0;
+                number_has_adjacent_symbol = false;
+            }
+        }
+        if number_started && number_has_adjacent_symbol {
+            numbers.push(number);
+        }
+    }
+    numbers
+}
+
+#[test]
+fn test_has_adjacent_symbol() {
+    assert!(!has_adjacent_symbol(
+        &read_board(
+            r#"467..114..
+...*......"#
+        )
+        .unwrap(),
+        (0, 5)
+    ));
+
+    assert!(has_adjacent_symbol(
+        &read_board(
+            r#"467..114..
+...*......"#
+        )
+        .unwrap(),
+        (0, 2)
+    ));
+
+    assert!(!has_adjacent_symbol(
+        &read_board(
+            r#"467..114..
+...*......"#
+        )
+        .unwrap(),
+        (0, 1)
+    ))
+}
+
+fn has_adjacent_symbol(board: &Board, coordinate: (u128, u128)) -> bool {
+    let walk_range = [-1, 0, 1];
+    let (x, y) = coordinate;
+    for &dx in &walk_range {
+        for &dy in &walk_range {
+            if dx == 0 && dy == 0 {
+                continue;
+            }
+            if let Some(row) = board.get((x as i32 + dx) as usize) {
+                if let Some(&candidate) = row.get((y as i32 + dy) as usize) {
+                    if is_symbol(candidate) {
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+    false
+}
+
+fn is_symbol(c: char) -> bool {
+    !c.is_numeric() && c != '.'
+}
+
+pub fn read_board(text: &str) -> Result<Board, ParseError> {
+    let mut board: Board = vec![];
+    for (i, line) in text.lines().enumerate() {
+        let mut row = vec![];
+        for (offset, c) in line.char_indices() {
+            if c.is_numeric() && !c.is_ascii_digit() {
+                let offending = &line[offset..offset + c.len_utf8()];
+                return Err(ParseError::at(
+                    line,
+                    offending,
+                    "only the digits 0-9 can be part numbers",
+                )
+                .on_line(i + 1));
+            }
+            row.push(c);
+        }
+        board.push(row);
+    }
+    Ok(board)
+}
+
+#[test]
+fn test_read_board() {
+    assert_eq!(
+        read_board("4.\n.*"),
+        Ok(vec![vec!['4', '.'], vec!['.', '*']])
+    );
+    assert_eq!(
+        read_board("467..\n..٣.*"),
+        Err(ParseError {
+            line: 2,
+            column: 3,
+            text: "٣".to_string(),
+            message: "only the digits 0-9 can be part numbers".to_string(),
+        })
+    );
+}
@@ -0,0 +1,146 @@
+use common::{ParseError, Solution, SolveError};
+use std::collections::HashSet;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| read_numbers(line).map_err(|err| err.on_line(i + 1)))
+            .collect()
+    }
+
+    fn part1(cards: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(total_points(cards))
+    }
+}
+
+common::samples! {
+    Day4,
+    sample: "sample.txt" => { part1: 13 },
+}
+
+/// The numbers on the lotto card and the winning numbers.
+pub type Card = (HashSet<u8>, HashSet<u8>);
+
+#[test]
+fn test_read_numbers() {
+    assert_eq!(
+        read_numbers("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"),
+        Ok((
+            [41, 48, 83, 86, 17].iter().cloned().collect(),
+            [83, 86, 6, 31, 17, 9, 48, 53].iter().cloned().collect(),
+        ))
+    );
+
+    assert_eq!(
+        read_numbers("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19"),
+        Ok((
+            [13, 32, 20, 16, 61].iter().cloned().collect(),
+            [61, 30, 68, 82, 17, 32, 24, 19].iter().cloned().collect(),
+        ))
+    );
+    assert_eq!(
+        read_numbers("Card 3: 1 21 53 59 44 | 69 82 63 72 16 21 14 1"),
+        Ok((
+            [1, 21, 53, 59, 44].iter().cloned().collect(),
+            [69, 82, 63, 72, 16, 21, 14, 1].iter().cloned().collect(),
+        ))
+    );
+    assert_eq!(
+        read_numbers("Card 4: 41 92 73 84 69 | 59 84 76 51 58 5 54 83"),
+        Ok((
+            [41, 92, 73, 84, 69].iter().cloned().collect(),
+            [59, 84, 76, 51, 58, 5, 54, 83].iter().cloned().collect(),
+        ))
+    );
+    assert_eq!(
+        read_numbers("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36"),
+        Ok((
+            [87, 83, 26, 28, 32].iter().cloned().collect(),
+            [88, 30, 70, 12, 93, 22, 82, 36].iter().cloned().collect(),
+        ))
+    );
+    assert_eq!(
+        read_numbers("Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"),
+        Ok((
+            [31, 18, 13, 56, 72].iter().cloned().collect(),
+            [74, 77, 10, 23, 35, 67, 36, 11].iter().cloned().collect(),
+        ))
+    );
+    assert_eq!(
+        read_numbers("Card 7: 31 18 | 74 x7 10"),
+        Err(ParseError {
+            line: 1,
+            column: 20,
+            text: "x7".to_string(),
+            message: "invalid card number".to_string(),
+        })
+    );
+    assert!(read_numbers("Card 8 31 18 | 74").is_err());
+    assert!(read_numbers("Card 9: 31 18 74").is_err());
+}
+
+fn read_numbers(line: &str) -> Result<Card, ParseError> {
+    let (_, numbers) = line
+        .split_once(':')
+        .ok_or_else(|| ParseError::at(line, line, "expected 'Card <id>: <numbers>'"))?;
+    let (lotto, winnin) = numbers
+        .split_once('|')
+        .ok_or_else(|| ParseError::at(line, numbers, "expected '|' between the two cards"))?;
+
+    let lotto_card = to_map(line, lotto)?;
+    let winnin_card = to_map(line, winnin)?;
+    Ok((lotto_card, winnin_card))
+}
+
+fn to_map(line: &str, numbers: &str) -> Result<HashSet<u8>, ParseError> {
+    let mut set = HashSet::new();
+
+    for number in numbers.split_whitespace() {
+        let number = number
+            .parse::<u8>()
+            .map_err(|_| ParseError::at(line, number, "invalid card number"))?;
+        set.insert(number);
+    }
+
+    Ok(set)
+}
+
+fn find_duplicates(lotto_card: &HashSet<u8>, winnin_card: &HashSet<u8>) -> HashSet<u8> {
+    let mut duplicates = HashSet::new();
+    for number in lotto_card.intersection(winnin_card) {
+        duplicates.insert(*number);
+    }
+    duplicates
+}
+
+fn count_points(duplicates: HashSet<u8>) -> u128 {
+    if duplicates.is_empty() {
+        return 0;
+    }
+    2_usize.pow((duplicates.len() - 1).try_into().unwrap()) as u128
+}
+
+pub fn card_points(card: &Card) -> u128 {
+    let numbers_with_win = find_duplicates(&card.0, &card.1);
+    count_points(numbers_with_win)
+}
+
+pub fn total_points(cards: &[Card]) -> u128 {
+    let mut total_points = 0;
+
+    for card in cards {
+        total_points += card_points(card);
+    }
+
+    total_points
+}